@@ -1,7 +1,7 @@
 use convert_case::{Casing, Case};
 use proc_macro2::{Span, Ident, TokenStream};
-use quote::{quote, TokenStreamExt, format_ident};
-use syn_v2::{DeriveInput, Data, spanned::Spanned, Field, parse_macro_input};
+use quote::{quote, quote_spanned, TokenStreamExt, format_ident};
+use syn_v2::{DeriveInput, Data, DataEnum, spanned::Spanned, Field, Fields, FieldMutability, Variant, Visibility, parse_macro_input, ext::IdentExt};
 
 use derive_attribute_utils::{TryFromMeta, Syn2, ArgResult, Error, ErrorMsg::{*, self}, SynVersion, Concat, GetSpan, AttributeName, Attribute, CustomArgFromMeta, CustomArg};
 
@@ -26,6 +26,9 @@ fn attempt_derive_attr(ast: DeriveInput) -> Result<TokenStream, Vec<syn_v2::Erro
     let mut all_errors = vec![];
 
     let maybe_container_attr = AttributeAttribute::from_attrs(ast.ident.span(), ast.attrs)?;
+    if let Err(ref mut errors) = maybe_container_attr.check_args(ATTRIBUTE_STRUCT_ARGS, "a struct") {
+        all_errors.append(errors);
+    }
 
     let struct_data =
         match ast.data {
@@ -38,11 +41,12 @@ fn attempt_derive_attr(ast: DeriveInput) -> Result<TokenStream, Vec<syn_v2::Erro
 
     let mut builder = AttributeTraitBuilder::new(ast.ident, maybe_container_attr);
     
-
+    let mut found_value_field = false;
+    let mut found_rest_field = false;
 
     for field in struct_data.fields {
         let field_attr = 
-            match AttributeAttribute::from_attrs(field.ident.span(), field.attrs.clone()) {
+            match AttributeAttribute::from_field_attrs(field.ident.span(), field.attrs.clone()) {
                 Ok(attr) => attr,
                 Err(ref mut errors) => {
                     all_errors.append(errors);
@@ -50,7 +54,25 @@ fn attempt_derive_attr(ast: DeriveInput) -> Result<TokenStream, Vec<syn_v2::Erro
                 }
             };
 
-        builder.check_field(field, field_attr);
+        if field_attr.value {
+            if found_value_field {
+                all_errors.push(syn_v2::Error::new(field.span(), "Only one field can be the attribute's value"));
+                continue;
+            }
+            found_value_field = true;
+        }
+
+        if field_attr.rest {
+            if found_rest_field {
+                all_errors.push(syn_v2::Error::new(field.span(), "Only one field can collect the rest of the arguments"));
+                continue;
+            }
+            found_rest_field = true;
+        }
+
+        if let Err(error) = builder.check_field(field, field_attr) {
+            all_errors.push(error);
+        }
         
     }
 
@@ -84,22 +106,29 @@ pub fn derive_list(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 fn attempt_derive_list(ast: DeriveInput) -> Result<TokenStream, Vec<syn_v2::Error>> {
     let mut all_errors = vec![];
 
+    let container_attr = AttributeAttribute::from_attrs(ast.ident.span(), ast.attrs)?;
+
     let struct_data =
         match ast.data {
             Data::Struct(struct_date) => struct_date,
+            Data::Enum(enum_data) => return attempt_derive_enum_list(ast.ident, enum_data, container_attr),
             _ => {
-                all_errors.push(syn_v2::Error::new(ast.ident.span(), "Invalid body expected struct"));
+                all_errors.push(syn_v2::Error::new(ast.ident.span(), "Invalid body expected struct or enum"));
                 return Err(all_errors)
             }
         };
+    if let Err(ref mut errors) = container_attr.check_args(LIST_STRUCT_ARGS, "a struct") {
+        all_errors.append(errors);
+    }
 
-    let mut builder = ListTraitBuilder::new(ast.ident);
+    let mut builder = ListTraitBuilder::new(ast.ident, container_attr);
     
+    let mut found_rest_field = false;
 
 
     for field in struct_data.fields {
         let field_attr = 
-            match AttributeAttribute::from_attrs(field.ident.span(), field.attrs.clone()) {
+            match AttributeAttribute::from_field_attrs(field.ident.span(), field.attrs.clone()) {
                 Ok(attr) => attr,
                 Err(ref mut errors) => {
                     all_errors.append(errors);
@@ -107,7 +136,22 @@ fn attempt_derive_list(ast: DeriveInput) -> Result<TokenStream, Vec<syn_v2::Erro
                 }
             };
 
-        builder.check_field(field, field_attr);
+        if field_attr.value {
+            all_errors.push(syn_v2::Error::new(field.span(), "Only attributes can have a value field"));
+            continue;
+        }
+
+        if field_attr.rest {
+            if found_rest_field {
+                all_errors.push(syn_v2::Error::new(field.span(), "Only one field can collect the rest of the arguments"));
+                continue;
+            }
+            found_rest_field = true;
+        }
+
+        if let Err(error) = builder.check_field(field, field_attr) {
+            all_errors.push(error);
+        }
         
     }
 
@@ -120,6 +164,143 @@ fn attempt_derive_list(ast: DeriveInput) -> Result<TokenStream, Vec<syn_v2::Erro
 }
 
 
+fn attempt_derive_enum_list(enum_name: Ident, enum_data: DataEnum, container_attr: AttributeAttribute) -> Result<TokenStream, Vec<syn_v2::Error>> {
+    let mut all_errors = vec![];
+
+    if let Err(ref mut errors) = container_attr.check_args(LIST_ENUM_ARGS, "an enum") {
+        all_errors.append(errors);
+    }
+
+    let mut builder = EnumListTraitBuilder::new(enum_name, container_attr.rename_all());
+
+    for variant in enum_data.variants {
+        let variant_attr = 
+            match AttributeAttribute::from_item_attrs(variant.ident.span(), variant.attrs.clone(), LIST_VARIANT_ARGS, "a variant") {
+                Ok(attr) => attr,
+                Err(ref mut errors) => {
+                    all_errors.append(errors);
+                    continue;
+                }
+            };
+
+        if let Err(ref mut errors) = builder.check_variant(variant, variant_attr) {
+            all_errors.append(errors);
+        }
+    }
+
+    let output = builder.build();
+
+    match all_errors.len() {
+        0 => Ok(output),
+        _ => Err(all_errors)
+    }
+}
+
+
+
+#[proc_macro_derive(AttrValue, attributes(attr))]
+pub fn derive_attr_value(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    let maybe_output = attempt_derive_value(ast);
+    let output = 
+        match maybe_output {
+            Ok(output) => output,
+            Err(errors) => {
+                let compile_errors = errors.into_iter().map(|e| e.to_compile_error());
+                quote!(#(#compile_errors)*)
+            }
+        };
+
+    output.into()
+}
+fn attempt_derive_value(ast: DeriveInput) -> Result<TokenStream, Vec<syn_v2::Error>> {
+    let mut all_errors = vec![];
+
+    let container_attr = AttributeAttribute::from_attrs(ast.ident.span(), ast.attrs)?;
+
+    let enum_data =
+        match ast.data {
+            Data::Enum(enum_data) => enum_data,
+            _ => {
+                all_errors.push(syn_v2::Error::new(ast.ident.span(), "Invalid body expected enum"));
+                return Err(all_errors)
+            }
+        };
+
+    if container_attr.untagged {
+        return attempt_derive_untagged_value(ast.ident, enum_data, container_attr);
+    }
+    if let Err(ref mut errors) = container_attr.check_args(VALUE_ENUM_ARGS, "an enum") {
+        all_errors.append(errors);
+    }
+
+    let mut builder = ValueTraitBuilder::new(ast.ident, container_attr);
+
+    for variant in enum_data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            all_errors.push(syn_v2::Error::new(variant.ident.span(), "Invalid variant expected unit variant"));
+            continue;
+        }
+
+        let variant_attr = 
+            match AttributeAttribute::from_item_attrs(variant.ident.span(), variant.attrs.clone(), VALUE_VARIANT_ARGS, "a variant") {
+                Ok(attr) => attr,
+                Err(ref mut errors) => {
+                    all_errors.append(errors);
+                    continue;
+                }
+            };
+
+        builder.check_variant(variant, variant_attr);
+    }
+
+    let output = builder.build();
+
+    match all_errors.len() {
+        0 => Ok(output),
+        _ => Err(all_errors)
+    }
+}
+
+
+fn attempt_derive_untagged_value(enum_name: Ident, enum_data: DataEnum, container_attr: AttributeAttribute) -> Result<TokenStream, Vec<syn_v2::Error>> {
+    let mut all_errors = vec![];
+
+    if let Err(ref mut errors) = container_attr.check_args(UNTAGGED_ENUM_ARGS, "an untagged enum") {
+        all_errors.append(errors);
+    }
+
+    let mut builder = UntaggedValueTraitBuilder::new(enum_name, container_attr.rename_all());
+
+    for variant in enum_data.variants {
+        if !matches!(variant.fields, Fields::Unnamed(ref fields) if fields.unnamed.len() == 1) {
+            all_errors.push(syn_v2::Error::new(variant.ident.span(), "Invalid variant expected newtype variant"));
+            continue;
+        }
+
+        let variant_attr = 
+            match AttributeAttribute::from_item_attrs(variant.ident.span(), variant.attrs.clone(), VALUE_VARIANT_ARGS, "a variant") {
+                Ok(attr) => attr,
+                Err(ref mut errors) => {
+                    all_errors.append(errors);
+                    continue;
+                }
+            };
+
+        builder.check_variant(variant, variant_attr);
+    }
+
+    let output = builder.build();
+
+    match all_errors.len() {
+        0 => Ok(output),
+        _ => Err(all_errors)
+    }
+}
+
+
 
 
 
@@ -149,11 +330,13 @@ impl BuilderParts {
             quote!{
                 struct #builder_name<V: SynVersion> {
                     #field_declaration
+                    _version: PhantomData<V>,
                 }
                 impl<V: SynVersion> #builder_name<V> {
                     fn new(location: Span) -> Self {
                         Self {
                             #field_expansion
+                            _version: PhantomData,
                         }
                     } 
                 }
@@ -173,24 +356,66 @@ impl BuilderParts {
 
 struct Validation {
     validate_arguments: TokenStream,
-    expansion: TokenStream
+    unwrap_fields: TokenStream,
+    bound_fields: Vec<(String, Ident)>,
+    computed_fields: Vec<(Ident, Computation)>,
+    expansion: TokenStream,
+    field_locations: TokenStream,
+    struct_validator: Option<syn_v2::Path>,
+    constrained_fields: Vec<ConstrainedField>,
+    one_of: Vec<Vec<(String, Span)>>,
+    at_least_one_of: Vec<Vec<(String, Span)>>
 }
 impl Validation {
     fn new() -> Self {
         Self {
             validate_arguments: TokenStream::new(),
-            expansion: TokenStream::new()
+            unwrap_fields: TokenStream::new(),
+            bound_fields: vec![],
+            computed_fields: vec![],
+            expansion: TokenStream::new(),
+            field_locations: TokenStream::new(),
+            struct_validator: None,
+            constrained_fields: vec![],
+            one_of: vec![],
+            at_least_one_of: vec![]
         }
     }
 }
 
+/// A field that can be named by `requires`, `conflicts_with` or a group of fields.
+struct ConstrainedField {
+    name: String,
+    field_name: Ident,
+    key: String,
+    span: Span,
+    requires: Vec<String>,
+    conflicts_with: Vec<String>
+}
+
 struct TryFrom {
-    match_branches: TokenStream
+    match_branches: TokenStream,
+    keys: Vec<String>,
+    positional_branches: TokenStream,
+    positional_count: usize,
+    value_branch: Option<TokenStream>,
+    flatten_branches: TokenStream,
+    flattened: Vec<(syn_v2::Type, String)>,
+    ignore_unknown: bool,
+    rest: Option<Ident>
 }
 impl TryFrom {
     fn new() -> Self {
         Self {
-            match_branches: TokenStream::new()
+            match_branches: TokenStream::new(),
+            keys: vec![],
+            positional_branches: TokenStream::new(),
+            positional_count: 0,
+            value_branch: None,
+            flatten_branches: TokenStream::new(),
+            flattened: vec![],
+            ignore_unknown: false,
+            rest: None
         }
     }
 }
@@ -200,22 +425,131 @@ struct MacroBase {
     builder_parts: BuilderParts,
     try_from: TryFrom,
     validation: Validation,
+    field_count: usize,
+    rename_all: Option<Case>,
 }
 impl MacroBase {
-    fn new(struct_name: Ident) -> Self {
+    fn new(struct_name: Ident, rename_all: Option<Case>) -> Self {
         Self {
             struct_name: struct_name.clone(),
             builder_parts: BuilderParts::new(&struct_name),
             try_from: TryFrom::new(),
-            validation: Validation::new()
+            validation: Validation::new(),
+            field_count: 0,
+            rename_all
         }
     }
 
-    fn check_field(&mut self, field: Field, attribute: AttributeAttribute) {
-        let Self { builder_parts, try_from, validation, ..} = self;
+    fn check_field(&mut self, field: Field, attribute: AttributeAttribute) -> Result<(), syn_v2::Error> {
+        let Self { builder_parts, try_from, validation, field_count, rename_all, ..} = self;
+        let mut attribute = attribute;
+
+        let field_index = *field_count;
+        *field_count += 1;
 
-        let field_name = field.ident.unwrap();
+        let field_span = field.span();
+
+        // fields of a tuple struct can only be given as positional arguments
+        let (field_name, field_member, is_tuple_field) = 
+            match field.ident {
+                Some(ident) => (ident.clone(), quote!(#ident), false),
+                None => {
+                    let index = syn_v2::Index::from(field_index);
+                    (format_ident!("field_{field_index}"), quote!(#index), true)
+                }
+            };
         let field_type = field.ty;
+
+        // every field but a computed one is set before the computations run so it can be one of their inputs
+        if attribute.compute.is_none() {
+            let field_key = 
+                match is_tuple_field {
+                    true => field_index.to_string(),
+                    false => field_name.unraw().to_string()
+                };
+            validation.bound_fields.push((field_key, field_name.clone()));
+        }
+
+        // skipped fields are never parsed, they're set once every other field is valid
+        if attribute.skip || attribute.compute.is_some() {
+            match attribute.compute {
+                Some(CustomArg(computation)) => validation.computed_fields.push((field_name.clone(), computation)),
+                None => {
+                    let value = 
+                        match attribute.default {
+                            Some(CustomArg(default)) => default.expand(&field_type),
+                            None => Default::UseSelfDefault.expand(&field_type)
+                        };
+                    validation.unwrap_fields.append_all(quote!{ let #field_name: #field_type = #value; });
+                }
+            }
+            validation.expansion.append_all(quote!{ #field_member: #field_name, });
+            return Ok(());
+        }
+
+        // the rest field collects the arguments whose key doesn't match any other field
+        if attribute.rest {
+            builder_parts.field_declaration.append_all(quote!{ #field_name: Vec<(String, V::ArgMeta)>, });
+            builder_parts.field_expansion.append_all(quote!{ #field_name: vec![], });
+            builder_parts.concat_parts.append_all(quote!{ self.#field_name.extend(other.#field_name); });
+
+            try_from.rest = Some(field_name.clone());
+
+            // the type is checked where it's written so a field that can't collect metadata points at it
+            let from_args = quote_spanned!(field_type.span()=> <#field_type as RestArgs>::from_args::<V>);
+            let field_error = format!("failed to deserialize '{}'", field_name.unraw());
+            let validate_field = 
+                quote!{
+                    let mut #field_name = #from_args(builder.#field_name);
+                    if let Err(ref mut errors) = #field_name {
+                        state.errors.append(errors);
+                    }
+                };
+            validation.validate_arguments.append_all(validate_field);
+            validation.unwrap_fields.append_all(quote!{ let #field_name = #field_name.expect(#field_error); });
+            validation.expansion.append_all(quote!{ #field_member: #field_name, });
+            return Ok(());
+        }
+
+        // the keys of a flattened field are matched by its parent and added to its builder
+        if attribute.flatten {
+            let flatten = quote!(<#field_type as Flatten>);
+            
+            // the list's builder is only created once one of its keys is found
+            builder_parts.field_declaration.append_all(quote!{ #field_name: ArgResult<<#field_type as TryFromMeta<V>>::InitialType>, });
+            builder_parts.field_expansion.append_all(quote!{ #field_name: ArgResult::new(location), });
+            builder_parts.concat_parts.append_all(quote!{ self.#field_name.merge_append(other.#field_name); });
+
+            let branch = 
+                quote!{
+                    flattened_key if #flatten::KEYS.contains(&flattened_key) => {
+                        let location = builder.#field_name.location;
+                        let flattened = builder.#field_name.value.get_or_insert_with(|| #flatten::new_builder::<V>(location));
+                        #flatten::add_arg::<V>(flattened, flattened_key, arg);
+                    }
+                };
+            try_from.flatten_branches.append_all(branch);
+            try_from.flattened.push((field_type.clone(), field_name.unraw().to_string()));
+
+            let field_name_str = field_name.unraw().to_string();
+            let field_error = format!("failed to deserialize '{field_name_str}'");
+            let validate_field = 
+                quote!{
+                    // a list without a default reports its own missing fields when none of its keys are given
+                    let mut flattened = builder.#field_name;
+                    if !flattened.is_found() && !#flatten::HAS_DEFAULT {
+                        flattened.add_value(#flatten::new_builder::<V>(flattened.location));
+                    }
+                    let mut #field_name = <#field_type as TryFromMeta<V>>::validate(flattened, #field_name_str);
+                    if let Err(ref mut errors) = #field_name {
+                        state.errors.append(errors);
+                    }
+                };
+            validation.validate_arguments.append_all(validate_field);
+            validation.unwrap_fields.append_all(quote!{ let #field_name = #field_name.expect(#field_error); });
+            validation.expansion.append_all(quote!{ #field_member: #field_name, });
+            return Ok(());
+        }
         
         {
             let field_decl = quote!{ #field_name: ArgResult<<#field_type as TryFromMeta<V>>::InitialType>, };
@@ -228,27 +562,136 @@ impl MacroBase {
         }
         
         {
-            let concat_part = quote!{self.#field_name.concat(other.#field_name);};
-            builder_parts.concat_parts.append_all(concat_part);
+            let field_key = 
+                match is_tuple_field {
+                    true => field_index.to_string(),
+                    false => field_name.unraw().to_string()
+                };
+            let field_location = quote!{ (#field_key, builder.#field_name.location), };
+            validation.field_locations.append_all(field_location);
         }
 
         let field_name_str = 
             match attribute.name {
                 Some(name) => name,
-                None => field_name.to_string()
+                None if is_tuple_field => format!("positional argument {}", try_from.positional_count + 1),
+                None => key_from_ident(&field_name, *rename_all)
+            };
+
+        // a merge strategy replaces the type's `Concat` implementation, a counted field starts at zero
+        // strategies that can find a duplicate are given the key to name in the error
+        let deserialize = 
+            match (attribute.eval, attribute.non_finite) {
+                (true, true) => {
+                    let location = attribute.args.iter().find(|(key, _)| key == "non_finite").map_or(field_span, |(_, location)| *location);
+                    return Err(syn_v2::Error::new(location, "'non_finite' can't be used with 'eval'"));
+                }
+                (true, false) => quote!(eval_occurrence::<V, #field_type>),
+                (false, true) => quote!(non_finite_occurrence::<V, #field_type>),
+                (false, false) => quote!(<#field_type as TryFromMeta<V>>::try_from_meta)
             };
+        let (merge, deserialize, merge_key) = 
+            match attribute.merge {
+                Some(CustomArg(MergeStrategy::Count)) => {
+                    attribute.default.get_or_insert(CustomArg(Default::UseSelfDefault));
+                    (MergeStrategy::Count.method(), quote!(count_occurrence::<V, #field_type>), quote!())
+                }
+                Some(CustomArg(MergeStrategy::Error)) => (MergeStrategy::Error.method(), deserialize, quote!(, #field_name_str)),
+                Some(CustomArg(strategy)) => (strategy.method(), deserialize, quote!()),
+                None => (format_ident!("concat_arg"), deserialize, quote!(, #field_name_str))
+            };
+
         {
+            let concat_part = quote!{self.#field_name.#merge(other.#field_name #merge_key);};
+            builder_parts.concat_parts.append_all(concat_part);
+        }
+
+        if !is_tuple_field {
+            // a key that's already matched by another field would never reach this one
+            let duplicate_key = 
+                std::iter::once(&field_name_str)
+                    .chain(&attribute.alias)
+                    .find(|key| try_from.keys.contains(key));
+            if let Some(key) = duplicate_key {
+                return Err(syn_v2::Error::new(field_name.span(), format!("Duplicate key '{key}' is already used by another field")));
+            }
+
+            let constrained_field = 
+                ConstrainedField {
+                    name: field_name.unraw().to_string(),
+                    field_name: field_name.clone(),
+                    key: field_name_str.clone(),
+                    span: field_span,
+                    requires: attribute.requires,
+                    conflicts_with: attribute.conflicts_with
+                };
+            validation.constrained_fields.push(constrained_field);
+
+            let add_arg = 
+                quote!{
+                    let value_location = arg.get_value_span();
+                    let value = #deserialize(arg).with_value_location(value_location);
+                    builder.#field_name.#merge(value #merge_key);
+                };
+
+            // a deprecated field warns under every key, `deprecated_alias` only warns when an alias is used
+            let warning = |note: Option<&String>| 
+                match note {
+                    Some(note) => quote!{ Warning::new(arg.get_span(), format!("'{key}' is deprecated: {}", #note)).emit(); },
+                    None => quote!()
+                };
+            let key_warning = warning(attribute.deprecated.as_ref());
+            let alias_warning = warning(attribute.deprecated_alias.as_ref().or(attribute.deprecated.as_ref()));
+
             let branch = 
                 quote!{
                     #field_name_str => {
-                        let value = <#field_type as TryFromMeta<V>>::try_from_meta(arg);
-                        builder.#field_name.concat(value);
+                        #key_warning
+                        #add_arg
                     }
                 };
             try_from.match_branches.append_all(branch);
+            try_from.keys.push(field_name_str.clone());
+
+            if !attribute.alias.is_empty() {
+                let aliases = &attribute.alias;
+                let branch = 
+                    quote!{
+                        #(#aliases)|* => {
+                            #alias_warning
+                            #add_arg
+                        }
+                    };
+                try_from.match_branches.append_all(branch);
+                try_from.keys.extend(attribute.alias.iter().cloned());
+            }
+        }
+
+        if is_tuple_field || attribute.positional {
+            let position = try_from.positional_count;
+            let branch = 
+                quote!{
+                    #position => {
+                        let value_location = arg.get_value_span();
+                        let value = #deserialize(arg).with_value_location(value_location);
+                        builder.#field_name.#merge(value #merge_key);
+                    }
+                };
+            try_from.positional_branches.append_all(branch);
+            try_from.positional_count += 1;
+        }
+
+        if attribute.value {
+            let branch = 
+                quote!{
+                    let value_location = value.get_value_span();
+                    let value = #deserialize(value).with_value_location(value_location);
+                    builder.#field_name.#merge(value #merge_key);
+                };
+            try_from.value_branch = Some(branch);
         }
    
-        let field_type_str = field_name.to_string();
+        let field_type_str = field_name_str.clone();
         {
             let normal_validation = 
                 quote!{
@@ -258,14 +701,10 @@ impl MacroBase {
                     }
                 };
 
-            let validate_field = 
+            let mut validate_field = 
                 match attribute.default {
                     Some(arg) => {
-                        let x = 
-                            match arg.0 {
-                                Default::UseSelfDefault => quote!{ <#field_type as Default>::default() },
-                                Default::ChooseDefault(path) => quote![ #path() ]
-                            };
+                        let x = arg.0.expand(&field_type);
 
                         quote!{
                             let mut #field_name = 
@@ -281,15 +720,88 @@ impl MacroBase {
                     }
                     None => normal_validation
                 };
+
+            let mut value_checks = TokenStream::new();
+
+            if let Some(CustomArg(ValueRange(range, range_str))) = attribute.range {
+                let check = 
+                    quote!{
+                        for value in ConstrainedValues::values(value) {
+                            if !in_range(#range, value) {
+                                state.errors.push(Error::new(value_location, OutOfRange { expected: #range_str, found: value.to_string() }));
+                            }
+                        }
+                    };
+                value_checks.append_all(check);
+            }
+
+            if let Some(CustomArg(ValueRange(range, range_str))) = attribute.len {
+                let check = 
+                    quote!{
+                        if let Some(length) = ConstrainedLength::length(value) {
+                            if !in_range(#range, &length) {
+                                state.errors.push(Error::new(value_location, InvalidLength { expected: #range_str, found: length }));
+                            }
+                        }
+                    };
+                value_checks.append_all(check);
+            }
+
+            // the pattern is checked when the macro is derived so it can't fail to compile here
+            if let Some(CustomArg(Pattern(pattern))) = attribute.pattern {
+                let check = 
+                    quote!{
+                        static PATTERN: std::sync::OnceLock<derive_attribute::reexports::regex::Regex> = std::sync::OnceLock::new();
+                        let pattern = PATTERN.get_or_init(|| derive_attribute::reexports::regex::Regex::new(#pattern).expect("invalid pattern"));
+                        for value in ConstrainedValues::values(value) {
+                            if !pattern.is_match(value) {
+                                state.errors.push(Error::new(value_location, PatternMismatch { pattern: #pattern, found: value.clone() }));
+                            }
+                        }
+                    };
+                value_checks.append_all(check);
+            }
+
+            if let Some(CustomArg(FnPath(validator))) = attribute.validate {
+                let check = 
+                    quote!{
+                        if let Err(error) = #validator(value) {
+                            state.errors.push(Error::new(value_location, ValidationError::into_error_msg(error)));
+                        }
+                    };
+                value_checks.append_all(check);
+            }
+
+            // checks are only given values that were deserialized, their errors use the location of the value
+            if !value_checks.is_empty() {
+                validate_field = 
+                    quote!{
+                        let mut #field_name = {
+                            let value_location = builder.#field_name.value_location;
+
+                            #validate_field
+
+                            if let Ok(ref value) = #field_name {
+                                #value_checks
+                            }
+
+                            #field_name
+                        };
+                    };
+            }
             validation.validate_arguments.append_all(validate_field);
         }
         
         {
             let field_error = format!("failed to deserialize '{field_name_str}'");
-            let field_expansion = quote!{ #field_name: #field_name.expect(#field_error), };
+            let unwrap_field = quote!{ let #field_name = #field_name.expect(#field_error); };
+            validation.unwrap_fields.append_all(unwrap_field);
+
+            let field_expansion = quote!{ #field_member: #field_name, };
             validation.expansion.append_all(field_expansion);
         }
-       
+
+        Ok(())
     }
 }
 
@@ -299,13 +811,19 @@ struct AttributeTraitBuilder {
 }
 impl AttributeTraitBuilder {
     fn new(struct_name: Ident, container_attr: AttributeAttribute) -> Self {
+        let mut base = MacroBase::new(struct_name, container_attr.rename_all());
+        base.try_from.ignore_unknown = container_attr.ignore_unknown;
+        base.validation.struct_validator = container_attr.validate.as_ref().map(|validator| validator.0.0.clone());
+        base.validation.one_of = container_attr.one_of.iter().map(|group| group.0.0.clone()).collect();
+        base.validation.at_least_one_of = container_attr.at_least_one_of.iter().map(|group| group.0.0.clone()).collect();
+
         Self {
-            container_attr,
-            base: MacroBase::new(struct_name)
+            base,
+            container_attr
         }
     }
-    fn check_field(&mut self, field: Field, attribute: AttributeAttribute) {
-        self.base.check_field(field, attribute);
+    fn check_field(&mut self, field: Field, attribute: AttributeAttribute) -> Result<(), syn_v2::Error> {
+        self.base.check_field(field, attribute)
     }
 
     fn build(self) -> TokenStream {
@@ -316,16 +834,12 @@ impl AttributeTraitBuilder {
                     struct_name, 
                     builder_parts, 
                     try_from, 
-                    validation
+                    validation,
+                    ..
                 } 
             } = self;
 
-        let set_default = 
-            match container_attr.default {
-                Some(CustomArg(Default::UseSelfDefault)) => quote!{ return Ok(<Self as Default>::default()) },
-                Some(CustomArg(Default::ChooseDefault(path))) => quote!{ return Ok(#path()) },
-                None => quote!()
-            };
+        let set_default = generate_set_default(container_attr.default);
 
         let (builder_decl, builder_name) = builder_parts.generate_builder();
         
@@ -357,12 +871,14 @@ impl AttributeTraitBuilder {
                 None => struct_name.to_string().to_case(Case::Snake)
             };
 
+        let flatten_checks = generate_flatten_checks(&try_from);
         let try_from_fn = generate_try_from_meta(format_ident!("deserialize_attr_args"), &builder_name, try_from);
         let validation_fn = generate_validate(validation, set_default, format_ident!("MissingAttribute"));
 
         quote!{
             const _: () = {
-                use derive_attribute::{AttributeName, TryFromMeta, Attribute, GetSpan, Concat, Error, ErrorMsg::*, SynVersion, ArgResult, reexports::proc_macro2::Span};
+                use derive_attribute::{AttributeName, TryFromMeta, Attribute, GetSpan, Concat, Error, ErrorMsg::*, Warning, SynVersion, ArgResult, Flatten, RestArgs, ValidationError, FieldSpans, ConstrainedValues, ConstrainedLength, in_range, count_occurrence, eval_occurrence, non_finite_occurrence, contains_key, keys_overlap, concat_keys, reexports::proc_macro2::Span};
+                use std::marker::PhantomData;
 
                 impl AttributeName for #struct_name {
                     const NAME: &'static str = #name;
@@ -372,6 +888,8 @@ impl AttributeTraitBuilder {
 
                 #builder_decl
 
+                #flatten_checks
+
                 impl<V: SynVersion> TryFromMeta<V> for #struct_name {
                     type InitialType = #builder_name<V>;
                 
@@ -390,16 +908,24 @@ impl AttributeTraitBuilder {
 
 
 struct ListTraitBuilder {
-    base: MacroBase
+    base: MacroBase,
+    default: Option<CustomArg<Default>>
 }
 impl ListTraitBuilder {
-    fn new(struct_name: Ident) -> Self {
+    fn new(struct_name: Ident, container_attr: AttributeAttribute) -> Self {
+        let mut base = MacroBase::new(struct_name, container_attr.rename_all());
+        base.try_from.ignore_unknown = container_attr.ignore_unknown;
+        base.validation.struct_validator = container_attr.validate.map(|validator| validator.0.0);
+        base.validation.one_of = container_attr.one_of.into_iter().map(|group| group.0.0).collect();
+        base.validation.at_least_one_of = container_attr.at_least_one_of.into_iter().map(|group| group.0.0).collect();
+
         Self {
-            base: MacroBase::new(struct_name)
+            base,
+            default: container_attr.default
         }
     }
-    fn check_field(&mut self, field: Field, attribute: AttributeAttribute) {
-        self.base.check_field(field, attribute);
+    fn check_field(&mut self, field: Field, attribute: AttributeAttribute) -> Result<(), syn_v2::Error> {
+        self.base.check_field(field, attribute)
     }
     fn build(self) -> TokenStream {
         let Self {
@@ -408,22 +934,35 @@ impl ListTraitBuilder {
                     struct_name, 
                     builder_parts, 
                     try_from, 
-                    validation
-                } 
+                    validation,
+                    ..
+                },
+            default
             } = self;
 
+        let has_default = default.is_some();
+        let set_default = generate_set_default(default);
+
         let (builder_decl, builder_name) = builder_parts.generate_builder();
+
+        let flatten_checks = generate_flatten_checks(&try_from);
+        let flatten_impl = generate_flatten(&struct_name, &try_from, has_default);
         
         let try_from_fn = generate_try_from_meta(format_ident!("deserialize_list_args"), &builder_name, try_from);
-        let validation_fn = generate_validate(validation, quote!(), format_ident!("MissingArg"));
+        let validation_fn = generate_validate(validation, set_default, format_ident!("MissingArg"));
 
         quote!{
             const _: () = {
-                use derive_attribute::{AttributeName, TryFromMeta, Attribute, GetSpan, Concat, Error, ErrorMsg::*, SynVersion, ArgResult, reexports::proc_macro2::Span};
+                use derive_attribute::{AttributeName, TryFromMeta, Attribute, GetSpan, Concat, Error, ErrorMsg::*, Warning, SynVersion, ArgResult, Flatten, RestArgs, ValidationError, FieldSpans, ConstrainedValues, ConstrainedLength, in_range, count_occurrence, eval_occurrence, non_finite_occurrence, contains_key, keys_overlap, concat_keys, reexports::proc_macro2::Span};
+                use std::marker::PhantomData;
 
 
                 #builder_decl
 
+                #flatten_checks
+
+                #flatten_impl
+
                 impl<V: SynVersion> TryFromMeta<V> for #struct_name {
                     type InitialType = #builder_name<V>;
                 
@@ -443,43 +982,745 @@ impl ListTraitBuilder {
 
 
 
-fn generate_try_from_meta(deserialize_args: Ident, builder_name: &Ident, try_from: TryFrom) -> TokenStream {
-    let TryFrom { match_branches } = try_from;
-    quote!{
-        fn try_from_meta(arg_meta: Self::Metadata) -> ArgResult<Self::InitialType> {
-            let mut result = ArgResult::new(arg_meta.get_span());
-    
-            let mut builder = #builder_name::new(arg_meta.get_span());
-    
-            let attribute_args = 
-                match V::#deserialize_args(&arg_meta) {
-                    Some(args) => args,
-                    None => {
-                        result.add_error(InvalidType { expected: "list" });
-                        return result
-                    }
-                };
-            
-    
-            for arg in attribute_args {
-                let key = V::deserialize_key(&arg).expect("key failed");
-                match key.as_str() {
-                    #match_branches
-
-                    _ => result.errors.push(Error::new(arg.get_span(), InvalidArg))
-                }
-            }
-            result.add_value(builder);
-            result
+/// Builds an externally tagged enum where the key of the argument chooses the variant. \
+/// Each variant is stored like an optional field of a list and only one of them can be found.
+struct EnumListTraitBuilder {
+    base: MacroBase,
+    variant_structs: TokenStream,
+    capture_locations: TokenStream,
+    selection: TokenStream,
+    variant_names: Vec<String>,
+    rename_all: Option<Case>
+}
+impl EnumListTraitBuilder {
+    fn new(enum_name: Ident, rename_all: Option<Case>) -> Self {
+        Self {
+            base: MacroBase::new(enum_name, None),
+            variant_structs: TokenStream::new(),
+            capture_locations: TokenStream::new(),
+            selection: TokenStream::new(),
+            variant_names: vec![],
+            rename_all
         }
     }
-}
+    fn check_variant(&mut self, variant: Variant, attribute: AttributeAttribute) -> Result<(), Vec<syn_v2::Error>> {
+        let enum_name = &self.base.struct_name;
+        let variant_name = variant.ident;
 
-fn generate_validate(validate: Validation, set_default: TokenStream, error_type: Ident) -> TokenStream {
-    let Validation { validate_arguments, expansion } = validate;
-    quote!{
-        fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
-            let mut state = state;
+        let variant_name_str = 
+            match attribute.name {
+                Some(name) => name,
+                None => key_from_ident(&variant_name, Some(self.rename_all.unwrap_or(Case::Snake)))
+            };
+
+        let field_name = format_ident!("variant_{}", self.variant_names.len());
+        let location_name = format_ident!("{field_name}_location");
+
+        let (field_type, construct) = 
+            match variant.fields {
+                Fields::Unit => {
+                    let construct = quote!{ if let Ok(true) = #field_name { found.push((#location_name, Self::#variant_name)); } };
+                    (quote!(bool), construct)
+                }
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    let inner_type = &fields.unnamed[0].ty;
+                    let construct = quote!{ if let Ok(Some(value)) = #field_name { found.push((#location_name, Self::#variant_name(value))); } };
+                    (quote!(Option<#inner_type>), construct)
+                }
+                Fields::Named(fields) => {
+                    // struct variants are deserialized through a hidden list struct with the same fields
+                    let fields_name = format_ident!("{enum_name}{variant_name}Fields");
+                    let mut list_builder = ListTraitBuilder::new(fields_name.clone(), AttributeAttribute::default());
+
+                    let mut all_errors = vec![];
+                    let mut field_declarations = TokenStream::new();
+                    let mut field_names = vec![];
+                    for field in fields.named {
+                        let field_attr = 
+                            match AttributeAttribute::from_field_attrs(field.ident.span(), field.attrs.clone()) {
+                                Ok(attr) => attr,
+                                Err(ref mut errors) => {
+                                    all_errors.append(errors);
+                                    continue;
+                                }
+                            };
+
+                        let name = &field.ident;
+                        let ty = &field.ty;
+                        field_declarations.append_all(quote!{ #name: #ty, });
+                        field_names.push(field.ident.clone());
+
+                        if let Err(error) = list_builder.check_field(field, field_attr) {
+                            all_errors.push(error);
+                        }
+                    }
+                    if !all_errors.is_empty() {
+                        return Err(all_errors);
+                    }
+
+                    let list_impl = list_builder.build();
+                    self.variant_structs.append_all(quote!{ 
+                        struct #fields_name { #field_declarations } 
+                        #list_impl
+                    });
+
+                    let construct = quote!{ if let Ok(Some(value)) = #field_name { found.push((#location_name, Self::#variant_name { #(#field_names: value.#field_names),* })); } };
+                    (quote!(Option<#fields_name>), construct)
+                }
+                _ => return Err(vec![syn_v2::Error::new(variant_name.span(), "Invalid variant expected unit, newtype or struct variant")])
+            };
+
+        let field = 
+            Field {
+                attrs: vec![],
+                vis: Visibility::Inherited,
+                mutability: FieldMutability::None,
+                ident: Some(field_name.clone()),
+                colon_token: None,
+                ty: syn_v2::parse_quote!(#field_type)
+            };
+        let field_attr = 
+            AttributeAttribute { 
+                name: Some(variant_name_str.clone()), 
+                alias: attribute.alias, 
+                deprecated: attribute.deprecated, 
+                deprecated_alias: attribute.deprecated_alias, 
+                ..AttributeAttribute::default() 
+            };
+        // the generated field has no span of its own
+        self.base.check_field(field, field_attr).map_err(|error| vec![syn_v2::Error::new(variant_name.span(), error)])?;
+
+        self.capture_locations.append_all(quote!{ let #location_name = builder.#field_name.location; });
+        self.selection.append_all(construct);
+        self.variant_names.push(variant_name_str);
+
+        Ok(())
+    }
+    fn build(self) -> TokenStream {
+        let Self {
+            base: 
+                MacroBase { 
+                    struct_name, 
+                    builder_parts, 
+                    try_from, 
+                    validation,
+                    ..
+                },
+            variant_structs,
+            capture_locations,
+            selection,
+            variant_names,
+            ..
+        } = self;
+
+        let (builder_decl, builder_name) = builder_parts.generate_builder();
+        
+        let try_from_fn = generate_try_from_meta(format_ident!("deserialize_list_args"), &builder_name, try_from);
+        let validate_arguments = validation.validate_arguments;
+
+        let quoted_names: Vec<String> = variant_names.iter().map(|name| format!("'{name}'")).collect();
+        let expected = quoted_names.join(", ");
+
+        quote!{
+            const _: () = {
+                use derive_attribute::{AttributeName, TryFromMeta, Attribute, GetSpan, Concat, Error, ErrorMsg::*, Warning, SynVersion, ArgResult, Flatten, RestArgs, ValidationError, FieldSpans, ConstrainedValues, ConstrainedLength, in_range, count_occurrence, eval_occurrence, non_finite_occurrence, contains_key, keys_overlap, concat_keys, reexports::proc_macro2::Span};
+                use std::marker::PhantomData;
+
+                #variant_structs
+
+                #builder_decl
+
+                impl<V: SynVersion> TryFromMeta<V> for #struct_name {
+                    type InitialType = #builder_name<V>;
+                
+                    type Metadata = V::ArgMeta;
+                    
+                    #try_from_fn
+                
+                    fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
+                        let mut state = state;
+
+                        let mut builder =
+                            match state.found_with_errors() {
+                                true => return Err(state.errors),
+                                false if state.value.is_none() => {
+                                    state.add_error(MissingArg(arg_name));
+                                    return Err(state.errors);
+                                }
+                                false => state.value.unwrap()
+                            };
+
+                        #capture_locations
+
+                        #validate_arguments
+
+                        if state.errors.len() > 0 {
+                            return Err(state.errors);
+                        }
+
+                        let mut found = vec![];
+                        #selection
+
+                        let mut found = found.into_iter();
+                        let variant = 
+                            match found.next() {
+                                Some((_, variant)) => variant,
+                                None => {
+                                    state.errors.push(Error::new(state.location, MissingVariant(#expected)));
+                                    return Err(state.errors);
+                                }
+                            };
+                        for (location, _) in found {
+                            state.errors.push(Error::new(location, ConflictingVariants(#expected)));
+                        }
+
+                        match state.errors.len() {
+                            0 => Ok(variant),
+                            _ => Err(state.errors)
+                        }
+                    }
+                }
+                
+            };
+        }
+    }
+}
+
+
+
+struct ValueTraitBuilder {
+    container_attr: AttributeAttribute,
+    enum_name: Ident,
+    name_branches: TokenStream,
+    discriminant_checks: TokenStream,
+    variant_names: Vec<String>
+}
+impl ValueTraitBuilder {
+    fn new(enum_name: Ident, container_attr: AttributeAttribute) -> Self {
+        Self {
+            container_attr,
+            enum_name,
+            name_branches: TokenStream::new(),
+            discriminant_checks: TokenStream::new(),
+            variant_names: vec![]
+        }
+    }
+    fn check_variant(&mut self, variant: Variant, attribute: AttributeAttribute) {
+        let variant_name = variant.ident;
+
+        let variant_name_str = 
+            match attribute.name {
+                Some(name) => name,
+                None => key_from_ident(&variant_name, Some(self.container_attr.rename_all().unwrap_or(Case::Snake)))
+            };
+
+        {
+            let branch = quote!{ #variant_name_str => Some(Self::#variant_name), };
+            self.name_branches.append_all(branch);
+        }
+
+        {
+            let check = quote!{ Some(value) if value == Self::#variant_name as i128 => Some(Self::#variant_name), };
+            self.discriminant_checks.append_all(check);
+        }
+
+        self.variant_names.push(variant_name_str);
+    }
+    fn build(self) -> TokenStream {
+        let Self { container_attr, enum_name, name_branches, discriminant_checks, variant_names } = self;
+
+        let maybe_discriminant = 
+            match container_attr.discriminant {
+                true => quote!{ 
+                    match V::deserialize_integer::<i128>(&meta).ok() {
+                        #discriminant_checks
+                        _ => None
+                    }
+                },
+                false => quote!(None)
+            };
+
+        let mut expected = 
+            match variant_names.len() {
+                1 => String::new(),
+                _ => String::from("one of ")
+            };
+        let quoted_names: Vec<String> = variant_names.iter().map(|name| format!("'{name}'")).collect();
+        expected.push_str(&quoted_names.join(", "));
+        if container_attr.discriminant {
+            expected.push_str(" or a discriminant");
+        }
+
+        quote!{
+            const _: () = {
+                use derive_attribute::{TryFromMeta, GetSpan, Concat, Error, ErrorMsg::*, SynVersion, ArgResult, required_validation};
+
+                impl Concat for #enum_name {}
+                impl<V: SynVersion> TryFromMeta<V> for #enum_name {
+                    type InitialType = Self;
+
+                    type Metadata = V::ArgMeta;
+
+                    fn try_from_meta(meta: Self::Metadata) -> ArgResult<Self::InitialType> {
+                        let mut result = ArgResult::new(meta.get_span());
+
+                        let maybe_name = V::deserialize_string(&meta).or_else(|| V::deserialize_ident(&meta));
+
+                        let maybe_variant = 
+                            match maybe_name {
+                                Some(name) => {
+                                    match name.as_str() {
+                                        #name_branches
+                                        _ => None
+                                    }
+                                }
+                                None => #maybe_discriminant
+                            };
+
+                        match maybe_variant {
+                            Some(variant) => result.add_value(variant),
+                            None => result.add_error(InvalidType { expected: #expected })
+                        }
+
+                        result
+                    }
+
+                    fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
+                        required_validation(state, arg_name)
+                    }
+                }
+            };
+        }
+    }
+}
+
+
+
+/// Builds an untagged enum that tries to deserialize each variant's type in order.
+struct UntaggedValueTraitBuilder {
+    enum_name: Ident,
+    rename_all: Option<Case>,
+    attempts: TokenStream
+}
+impl UntaggedValueTraitBuilder {
+    fn new(enum_name: Ident, rename_all: Option<Case>) -> Self {
+        Self {
+            enum_name,
+            rename_all,
+            attempts: TokenStream::new()
+        }
+    }
+    fn check_variant(&mut self, variant: Variant, attribute: AttributeAttribute) {
+        let variant_name = variant.ident;
+        let variant_type = &variant.fields.iter().next().expect("newtype variant").ty;
+
+        // used as the expected shape when the variant's type doesn't report one
+        let variant_name_str = 
+            match attribute.name {
+                Some(name) => name,
+                None => key_from_ident(&variant_name, Some(self.rename_all.unwrap_or(Case::Snake)))
+            };
+
+        let attempt = 
+            quote!{
+                let attempt = <#variant_type as TryFromMeta<V>>::try_from_meta(meta.clone());
+                match <#variant_type as TryFromMeta<V>>::validate(attempt, ARG_NAME) {
+                    Ok(value) => {
+                        result.add_value(Self::#variant_name(value));
+                        return result;
+                    }
+                    Err(errors) => {
+                        let shapes = 
+                            match errors.first().map(|error| &error.msg) {
+                                Some(InvalidType { expected }) => vec![*expected],
+                                Some(InvalidTypes { expected }) => expected.clone(),
+                                _ => vec![#variant_name_str]
+                            };
+                        all_expected.extend(shapes);
+                    }
+                }
+            };
+        self.attempts.append_all(attempt);
+    }
+    fn build(self) -> TokenStream {
+        let Self { enum_name, attempts, .. } = self;
+
+        let arg_name = enum_name.to_string().to_case(Case::Snake);
+
+        quote!{
+            const _: () = {
+                use derive_attribute::{TryFromMeta, GetSpan, Concat, Error, ErrorMsg::*, SynVersion, ArgResult, required_validation};
+
+                const ARG_NAME: &'static str = #arg_name;
+
+                impl Concat for #enum_name {}
+                impl<V: SynVersion> TryFromMeta<V> for #enum_name {
+                    type InitialType = Self;
+
+                    type Metadata = V::ArgMeta;
+
+                    fn try_from_meta(meta: Self::Metadata) -> ArgResult<Self::InitialType> {
+                        let mut result = ArgResult::new(meta.get_span());
+
+                        let mut all_expected = vec![];
+
+                        #attempts
+
+                        result.add_error(InvalidTypes { expected: all_expected });
+                        result
+                    }
+
+                    fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
+                        required_validation(state, arg_name)
+                    }
+                }
+            };
+        }
+    }
+}
+
+
+
+/// Gets the key of a field or variant without its raw identifier prefix EX) `r#type` is `type`.
+fn key_from_ident(ident: &Ident, rename_all: Option<Case>) -> String {
+    let name = ident.unraw().to_string();
+    match rename_all {
+        Some(case) => name.to_case(case),
+        None => name
+    }
+}
+
+/// Generates the implementation that lets a list be flattened into another list or attribute.
+fn generate_flatten(struct_name: &Ident, try_from: &TryFrom, has_default: bool) -> TokenStream {
+    let TryFrom { match_branches, keys, flatten_branches, flattened, .. } = try_from;
+    let flattened_types: Vec<_> = flattened.iter().map(|(flattened_type, _)| flattened_type).collect();
+    let key_count = keys.len();
+
+    quote!{
+        impl Flatten for #struct_name {
+            const KEYS: &'static [&'static str] = {
+                const LENGTH: usize = #key_count #(+ <#flattened_types as Flatten>::KEYS.len())*;
+                const ALL_KEYS: [&'static str; LENGTH] = concat_keys(&[&[#(#keys),*] #(, <#flattened_types as Flatten>::KEYS)*]);
+                &ALL_KEYS
+            };
+
+            const HAS_DEFAULT: bool = #has_default;
+
+            fn new_builder<V: SynVersion>(location: Span) -> <Self as TryFromMeta<V>>::InitialType {
+                <Self as TryFromMeta<V>>::InitialType::new(location)
+            }
+
+            fn add_arg<V: SynVersion>(builder: &mut <Self as TryFromMeta<V>>::InitialType, key: &str, arg: V::ArgMeta) {
+                match key {
+                    #match_branches
+
+                    #flatten_branches
+
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Generates compile time checks that the keys of flattened fields don't collide with any other key.
+fn generate_flatten_checks(try_from: &TryFrom) -> TokenStream {
+    let mut checks = TokenStream::new();
+
+    for (i, (flattened_type, field_name)) in try_from.flattened.iter().enumerate() {
+        let span = flattened_type.span();
+
+        for key in &try_from.keys {
+            let msg = format!("the key '{key}' is also used by the flattened field '{field_name}'");
+            let check = quote_spanned!{span=> const _: () = assert!(!contains_key(<#flattened_type as Flatten>::KEYS, #key), #msg); };
+            checks.append_all(check);
+        }
+
+        for (other_type, other_name) in &try_from.flattened[..i] {
+            let msg = format!("the flattened fields '{other_name}' and '{field_name}' share a key");
+            let check = quote_spanned!{span=> const _: () = assert!(!keys_overlap(<#other_type as Flatten>::KEYS, <#flattened_type as Flatten>::KEYS), #msg); };
+            checks.append_all(check);
+        }
+    }
+
+    checks
+}
+
+/// Generates the checks of `requires`, `conflicts_with`, `one_of` and `at_least_one_of` on the arguments that were found. \
+/// A constraint that names a field that doesn't exist becomes a compile error.
+fn generate_constraints(fields: &[ConstrainedField], one_of: &[Vec<(String, Span)>], at_least_one_of: &[Vec<(String, Span)>]) -> TokenStream {
+    let mut checks = TokenStream::new();
+
+    let find_field = |name: &String, span: Span, checks: &mut TokenStream| {
+        let found = fields.iter().find(|field| &field.name == name);
+        if found.is_none() {
+            let error = syn_v2::Error::new(span, format!("Unknown field '{name}', only named fields that are deserialized can be constrained"));
+            checks.append_all(error.to_compile_error());
+        }
+        found
+    };
+
+    let mut conflicting_pairs = vec![];
+    for field in fields {
+        let ConstrainedField { field_name, key, span, .. } = field;
+
+        for required in &field.requires {
+            let Some(other) = find_field(required, *span, &mut checks) else { continue };
+            let (other_name, other_key) = (&other.field_name, &other.key);
+
+            let check = 
+                quote!{
+                    if builder.#field_name.is_found() && !builder.#other_name.is_found() {
+                        state.errors.push(Error::new(builder.#field_name.location, RequiredBy { arg: #key, requires: #other_key }));
+                    }
+                };
+            checks.append_all(check);
+        }
+
+        // a conflict is reported on both arguments, once even if both fields declare it
+        for conflicting in &field.conflicts_with {
+            let Some(other) = find_field(conflicting, *span, &mut checks) else { continue };
+            let (other_name, other_key) = (&other.field_name, &other.key);
+
+            let pair = if key < other_key { (key, other_key) } else { (other_key, key) };
+            if conflicting_pairs.contains(&pair) {
+                continue;
+            }
+            conflicting_pairs.push(pair);
+
+            let check = 
+                quote!{
+                    if builder.#field_name.is_found() && builder.#other_name.is_found() {
+                        state.errors.push(Error::new(builder.#field_name.location, ConflictingArgs(#key, #other_key)));
+                        state.errors.push(Error::new(builder.#other_name.location, ConflictingArgs(#other_key, #key)));
+                    }
+                };
+            checks.append_all(check);
+        }
+    }
+
+    let groups = one_of.iter().map(|group| (group, true)).chain(at_least_one_of.iter().map(|group| (group, false)));
+    for (group, exactly_one) in groups {
+        let group_fields: Vec<_> = group.iter().filter_map(|(name, span)| find_field(name, *span, &mut checks)).collect();
+        if group_fields.len() != group.len() {
+            continue;
+        }
+        let field_names: Vec<_> = group_fields.iter().map(|field| &field.field_name).collect();
+
+        let quoted_keys: Vec<String> = group_fields.iter().map(|field| format!("'{}'", field.key)).collect();
+        let expected = quoted_keys.join(", ");
+
+        let missing_error = 
+            match exactly_one {
+                true => quote!(MissingOneOf(#expected)),
+                false => quote!(MissingAtLeastOneOf(#expected))
+            };
+        let check_conflicts = 
+            match exactly_one {
+                true => quote!{
+                    if found.len() > 1 {
+                        for location in found {
+                            state.errors.push(Error::new(location, ConflictingOneOf(#expected)));
+                        }
+                    }
+                },
+                false => quote!()
+            };
+
+        let check = 
+            quote!{
+                {
+                    let found: Vec<Span> = 
+                        [#((builder.#field_names.is_found(), builder.#field_names.location)),*]
+                            .into_iter()
+                            .filter_map(|(is_found, location)| is_found.then_some(location))
+                            .collect();
+                    if found.is_empty() {
+                        state.errors.push(Error::new(state.location, #missing_error));
+                    }
+                    #check_conflicts
+                }
+            };
+        checks.append_all(check);
+    }
+
+    checks
+}
+
+fn generate_try_from_meta(deserialize_args: Ident, builder_name: &Ident, try_from: TryFrom) -> TokenStream {
+    let TryFrom { match_branches, keys, positional_branches, positional_count, value_branch, flatten_branches, flattened, ignore_unknown, rest } = try_from;
+
+    let unknown_branch = 
+        match (rest, ignore_unknown) {
+            (Some(rest_field), _) => quote!{ unknown_key => builder.#rest_field.push((unknown_key.to_string(), arg)) },
+            (None, true) => quote!{ _ => {} },
+            (None, false) => quote!{ _ => result.errors.push(Error::new(arg.get_span(), InvalidArg)) }
+        };
+
+    // an attribute with a value field can also be written as `#[name = value]`
+    let missing_args = 
+        match value_branch {
+            Some(branch) => 
+                quote!{
+                    match V::deserialize_attr_value(&arg_meta) {
+                        Some(value) => {
+                            #branch
+                            result.add_value(builder);
+                            return result
+                        }
+                        None => {
+                            result.add_error(InvalidType { expected: "list" });
+                            return result
+                        }
+                    }
+                },
+            None => 
+                quote!{
+                    result.add_error(InvalidType { expected: "list" });
+                    return result
+                }
+        };
+
+    let (positional_state, check_positional) = 
+        match positional_count {
+            0 => (quote!(), quote!()),
+            _ => {
+                let mut is_key = 
+                    match keys.len() {
+                        0 => quote!(false),
+                        _ => quote!(matches!(maybe_key.as_deref(), Some(#(#keys)|*)))
+                    };
+                for (flattened_type, _) in &flattened {
+                    is_key.append_all(quote!{ || matches!(maybe_key.as_deref(), Some(key) if <#flattened_type as Flatten>::KEYS.contains(&key)) });
+                }
+
+                let state = 
+                    quote!{
+                        let mut positional_index = 0;
+                        let mut found_named = false;
+                    };
+
+                // bare paths are only positional if they aren't a key and there are positional arguments left
+                let check = 
+                    quote!{
+                        let is_key = #is_key;
+                        let is_positional = maybe_key.is_none() || (!is_key && positional_index < #positional_count && V::is_path(&arg));
+                        if is_positional {
+                            if found_named {
+                                result.errors.push(Error::new(arg.get_span(), PositionalAfterNamed));
+                                continue;
+                            }
+                            match positional_index {
+                                #positional_branches
+                                _ => result.errors.push(Error::new(arg.get_span(), TooManyPositional(#positional_count)))
+                            }
+                            positional_index += 1;
+                            continue;
+                        }
+                        found_named = true;
+                    };
+
+                (state, check)
+            }
+        };
+
+    quote!{
+        fn try_from_meta(arg_meta: Self::Metadata) -> ArgResult<Self::InitialType> {
+            let mut result = ArgResult::new(arg_meta.get_span());
+    
+            let mut builder = #builder_name::new(arg_meta.get_span());
+    
+            let attribute_args = 
+                match V::#deserialize_args(&arg_meta) {
+                    Some(args) => args,
+                    None => {
+                        #missing_args
+                    }
+                };
+            
+            #positional_state
+    
+            for arg in attribute_args {
+                let maybe_key = V::deserialize_key(&arg);
+
+                #check_positional
+
+                let key = 
+                    match maybe_key {
+                        Some(key) => key,
+                        None => {
+                            result.errors.push(Error::new(arg.get_span(), InvalidArg));
+                            continue;
+                        }
+                    };
+                match key.as_str() {
+                    #match_branches
+
+                    #flatten_branches
+
+                    #unknown_branch
+                }
+            }
+            result.add_value(builder);
+            result
+        }
+    }
+}
+
+/// Creates a statement that returns the container's default value when its argument isn't found.
+fn generate_set_default(default: Option<CustomArg<Default>>) -> TokenStream {
+    match default {
+        Some(CustomArg(default)) => {
+            let value = default.expand(&syn_v2::parse_quote!(Self));
+            quote!{ return Ok(#value) }
+        }
+        None => quote!()
+    }
+}
+
+fn generate_validate(validate: Validation, set_default: TokenStream, error_type: Ident) -> TokenStream {
+    let Validation { validate_arguments, unwrap_fields, bound_fields, computed_fields, expansion, field_locations, struct_validator, constrained_fields, one_of, at_least_one_of } = validate;
+
+    let constraints = generate_constraints(&constrained_fields, &one_of, &at_least_one_of);
+
+    // the struct validator is given the location of each field so its errors can point at them
+    let (capture_locations, validate_struct) = 
+        match struct_validator {
+            Some(validator) => {
+                let capture = quote!{ let field_spans = FieldSpans::new(state.location, vec![#field_locations]); };
+                let validate = 
+                    quote!{
+                        if let Err(mut errors) = #validator(&value, &field_spans) {
+                            state.errors.append(&mut errors);
+                            return Err(state.errors);
+                        }
+                    };
+                (capture, validate)
+            }
+            None => (quote!(), quote!())
+        };
+
+    // computed fields are given a reference to each field named in their call
+    let computations = 
+        computed_fields.into_iter().map(|(field_name, Computation { path, inputs, span })| {
+            let mut arguments = vec![];
+            for input in inputs {
+                match bound_fields.iter().find(|(key, _)| key == &input) {
+                    Some((_, bound_name)) => arguments.push(bound_name),
+                    None => {
+                        let message = format!("Unknown field '{input}', a computation can only be given fields that aren't computed");
+                        // the field is still bound so the unknown input is the only error
+                        let error = syn_v2::Error::new(span, message).to_compile_error();
+                        return quote!{ #error let #field_name = ::core::option::Option::None.unwrap(); };
+                    }
+                }
+            }
+            quote_spanned!{span=> let #field_name = #path(#(&#arguments),*); }
+        });
+
+    quote!{
+        fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
+            let mut state = state;
 
             if state.value.is_none() && state.found_with_errors() == false {
                 #set_default
@@ -501,11 +1742,23 @@ fn generate_validate(validate: Validation, set_default: TokenStream, error_type:
                     false => state.value.unwrap()
                 };
 
+            #capture_locations
+
+            #constraints
+
             #validate_arguments
     
     
             match state.errors.len() {
-                0 => Ok(Self { #expansion }),
+                0 => {
+                    #unwrap_fields
+                    #(#computations)*
+
+                    let value = Self { #expansion };
+                    #validate_struct
+
+                    Ok(value)
+                }
                 _ => Err(state.errors)
             }
         }
@@ -518,25 +1771,190 @@ fn generate_validate(validate: Validation, set_default: TokenStream, error_type:
 struct AttributeAttribute {
     name: Option<String>,
     default: Option<CustomArg<Default>>,
+    discriminant: bool,
+    untagged: bool,
+    positional: bool,
+    value: bool,
+    rename_all: Option<CustomArg<RenameAll>>,
+    alias: Vec<String>,
+    deprecated: Option<String>,
+    deprecated_alias: Option<String>,
+    skip: bool,
+    compute: Option<CustomArg<Computation>>,
+    flatten: bool,
+    ignore_unknown: bool,
+    rest: bool,
+    validate: Option<CustomArg<FnPath>>,
+    requires: Vec<String>,
+    conflicts_with: Vec<String>,
+    one_of: Vec<CustomArg<FieldGroup>>,
+    at_least_one_of: Vec<CustomArg<FieldGroup>>,
+    range: Option<CustomArg<ValueRange>>,
+    len: Option<CustomArg<ValueRange>>,
+    pattern: Option<CustomArg<Pattern>>,
+    merge: Option<CustomArg<MergeStrategy>>,
+    non_finite: bool,
+    eval: bool,
+    /// Every option that was given and its location.
+    args: Vec<(String, Span)>,
+}
+impl AttributeAttribute {
+    fn rename_all(&self) -> Option<Case> {
+        self.rename_all.as_ref().map(|rename_all| rename_all.0.0)
+    }
+
+    /// Parses the attribute and rejects any option the item doesn't use.
+    fn from_item_attrs(location: Span, attrs: Vec<syn_v2::Attribute>, allowed: &[&str], item: &str) -> Result<Self, Vec<syn_v2::Error>> {
+        let attribute = Self::from_attrs(location, attrs)?;
+        attribute.check_args(allowed, item)?;
+        Ok(attribute)
+    }
+
+    /// Parses a field's attribute and rejects any option the field doesn't use EX) `validate` on a skipped field.
+    fn from_field_attrs(location: Span, attrs: Vec<syn_v2::Attribute>) -> Result<Self, Vec<syn_v2::Error>> {
+        let attribute = Self::from_attrs(location, attrs)?;
+
+        // these fields are never deserialized like the others so most options would be ignored
+        let (allowed, item) = 
+            match attribute {
+                Self { compute: Some(_), .. } => (COMPUTED_FIELD_ARGS, "a computed field"),
+                Self { skip: true, .. } => (SKIPPED_FIELD_ARGS, "a skipped field"),
+                Self { rest: true, .. } => (REST_FIELD_ARGS, "a rest field"),
+                Self { flatten: true, .. } => (FLATTENED_FIELD_ARGS, "a flattened field"),
+                _ => (FIELD_ARGS, "a field")
+            };
+
+        attribute.check_args(allowed, item)?;
+        Ok(attribute)
+    }
+
+    /// Returns an error for each option the item doesn't use EX) `requires` on a struct.
+    fn check_args(&self, allowed: &[&str], item: &str) -> Result<(), Vec<syn_v2::Error>> {
+        let errors: Vec<_> = 
+            self.args.iter()
+                .filter(|(key, _)| !allowed.contains(&key.as_str()))
+                .map(|(key, location)| syn_v2::Error::new(*location, format!("'{key}' can't be used on {item}")))
+                .collect();
+
+        match errors.len() {
+            0 => Ok(()),
+            _ => Err(errors)
+        }
+    }
 }
 
+// the options each item uses, any other option would be silently ignored so it's an error
+const ATTRIBUTE_STRUCT_ARGS: &[&str] = &["name", "default", "default_expr", "rename_all", "ignore_unknown", "validate", "one_of", "at_least_one_of"];
+const LIST_STRUCT_ARGS: &[&str] = &["default", "default_expr", "rename_all", "ignore_unknown", "validate", "one_of", "at_least_one_of"];
+const LIST_ENUM_ARGS: &[&str] = &["rename_all"];
+const VALUE_ENUM_ARGS: &[&str] = &["rename_all", "discriminant", "untagged"];
+const FIELD_ARGS: &[&str] = &[
+    "name", "default", "default_expr", "positional", "value", "alias", "deprecated", "deprecated_alias", "skip", "compute", 
+    "flatten", "rest", "validate", "requires", "conflicts_with", "range", "len", "pattern", "merge", "non_finite", "eval"
+];
+const SKIPPED_FIELD_ARGS: &[&str] = &["skip", "default", "default_expr"];
+const COMPUTED_FIELD_ARGS: &[&str] = &["compute", "skip"];
+const REST_FIELD_ARGS: &[&str] = &["rest"];
+const FLATTENED_FIELD_ARGS: &[&str] = &["flatten"];
+const UNTAGGED_ENUM_ARGS: &[&str] = &["untagged", "rename_all"];
+const LIST_VARIANT_ARGS: &[&str] = &["name", "alias", "deprecated", "deprecated_alias"];
+const VALUE_VARIANT_ARGS: &[&str] = &["name"];
+
 struct AttributeAttributeBuilder<V: SynVersion> {
     name: ArgResult<<Option<String> as TryFromMeta<V>>::InitialType>,
     default: ArgResult<<Option<CustomArg<Default>> as TryFromMeta<V>>::InitialType>,
+    default_expr: ArgResult<<Option<CustomArg<DefaultExpr>> as TryFromMeta<V>>::InitialType>,
+    discriminant: ArgResult<<bool as TryFromMeta<V>>::InitialType>,
+    untagged: ArgResult<<bool as TryFromMeta<V>>::InitialType>,
+    positional: ArgResult<<bool as TryFromMeta<V>>::InitialType>,
+    value: ArgResult<<bool as TryFromMeta<V>>::InitialType>,
+    rename_all: ArgResult<<Option<CustomArg<RenameAll>> as TryFromMeta<V>>::InitialType>,
+    alias: ArgResult<<Vec<String> as TryFromMeta<V>>::InitialType>,
+    deprecated: ArgResult<<Option<String> as TryFromMeta<V>>::InitialType>,
+    deprecated_alias: ArgResult<<Option<String> as TryFromMeta<V>>::InitialType>,
+    skip: ArgResult<<bool as TryFromMeta<V>>::InitialType>,
+    compute: ArgResult<<Option<CustomArg<Computation>> as TryFromMeta<V>>::InitialType>,
+    flatten: ArgResult<<bool as TryFromMeta<V>>::InitialType>,
+    ignore_unknown: ArgResult<<bool as TryFromMeta<V>>::InitialType>,
+    rest: ArgResult<<bool as TryFromMeta<V>>::InitialType>,
+    validate: ArgResult<<Option<CustomArg<FnPath>> as TryFromMeta<V>>::InitialType>,
+    requires: ArgResult<<Vec<String> as TryFromMeta<V>>::InitialType>,
+    conflicts_with: ArgResult<<Vec<String> as TryFromMeta<V>>::InitialType>,
+    one_of: ArgResult<<Vec<CustomArg<FieldGroup>> as TryFromMeta<V>>::InitialType>,
+    at_least_one_of: ArgResult<<Vec<CustomArg<FieldGroup>> as TryFromMeta<V>>::InitialType>,
+    range: ArgResult<<Option<CustomArg<ValueRange>> as TryFromMeta<V>>::InitialType>,
+    len: ArgResult<<Option<CustomArg<ValueRange>> as TryFromMeta<V>>::InitialType>,
+    pattern: ArgResult<<Option<CustomArg<Pattern>> as TryFromMeta<V>>::InitialType>,
+    merge: ArgResult<<Option<CustomArg<MergeStrategy>> as TryFromMeta<V>>::InitialType>,
+    non_finite: ArgResult<<bool as TryFromMeta<V>>::InitialType>,
+    eval: ArgResult<<bool as TryFromMeta<V>>::InitialType>,
+    args: Vec<(String, Span)>,
 }
 impl<V: SynVersion> AttributeAttributeBuilder<V> {
     fn new(location: Span) -> Self {
         Self { 
             name: ArgResult::new(location),
             default: ArgResult::new(location),
+            default_expr: ArgResult::new(location),
+            discriminant: ArgResult::new(location),
+            untagged: ArgResult::new(location),
+            positional: ArgResult::new(location),
+            value: ArgResult::new(location),
+            rename_all: ArgResult::new(location),
+            alias: ArgResult::new(location),
+            deprecated: ArgResult::new(location),
+            deprecated_alias: ArgResult::new(location),
+            skip: ArgResult::new(location),
+            compute: ArgResult::new(location),
+            flatten: ArgResult::new(location),
+            ignore_unknown: ArgResult::new(location),
+            rest: ArgResult::new(location),
+            validate: ArgResult::new(location),
+            requires: ArgResult::new(location),
+            conflicts_with: ArgResult::new(location),
+            one_of: ArgResult::new(location),
+            at_least_one_of: ArgResult::new(location),
+            range: ArgResult::new(location),
+            len: ArgResult::new(location),
+            pattern: ArgResult::new(location),
+            merge: ArgResult::new(location),
+            non_finite: ArgResult::new(location),
+            eval: ArgResult::new(location),
+            args: vec![],
         }
     }
 }
 impl<V: SynVersion> Concat for AttributeAttributeBuilder<V> {
     const NO_DUPLICATES: bool = false;
     fn concat(&mut self, other: Self) {
-        self.name.concat(other.name);
-        self.default.concat(other.default);
+        self.name.concat_arg(other.name, "name");
+        self.default.concat_arg(other.default, "default");
+        self.default_expr.concat_arg(other.default_expr, "default_expr");
+        self.discriminant.concat_arg(other.discriminant, "discriminant");
+        self.untagged.concat_arg(other.untagged, "untagged");
+        self.positional.concat_arg(other.positional, "positional");
+        self.value.concat_arg(other.value, "value");
+        self.rename_all.concat_arg(other.rename_all, "rename_all");
+        self.alias.concat_arg(other.alias, "alias");
+        self.deprecated.concat_arg(other.deprecated, "deprecated");
+        self.deprecated_alias.concat_arg(other.deprecated_alias, "deprecated_alias");
+        self.skip.concat_arg(other.skip, "skip");
+        self.compute.concat_arg(other.compute, "compute");
+        self.flatten.concat_arg(other.flatten, "flatten");
+        self.ignore_unknown.concat_arg(other.ignore_unknown, "ignore_unknown");
+        self.rest.concat_arg(other.rest, "rest");
+        self.validate.concat_arg(other.validate, "validate");
+        self.requires.concat_arg(other.requires, "requires");
+        self.conflicts_with.concat_arg(other.conflicts_with, "conflicts_with");
+        self.one_of.concat_arg(other.one_of, "one_of");
+        self.at_least_one_of.concat_arg(other.at_least_one_of, "at_least_one_of");
+        self.range.concat_arg(other.range, "range");
+        self.len.concat_arg(other.len, "len");
+        self.pattern.concat_arg(other.pattern, "pattern");
+        self.merge.concat_arg(other.merge, "merge");
+        self.non_finite.concat_arg(other.non_finite, "non_finite");
+        self.eval.concat_arg(other.eval, "eval");
+        self.args.extend(other.args);
     }
 }
 
@@ -565,21 +1983,132 @@ impl<V: SynVersion> TryFromMeta<V> for AttributeAttribute {
 
 
         for arg in attribute_args {
-            let key = V::deserialize_key(&arg).expect("key failed");
+            let key = 
+                match V::deserialize_key(&arg) {
+                    Some(key) => key,
+                    None => {
+                        result.errors.push(Error::new(arg.get_span(), InvalidArg));
+                        continue;
+                    }
+                };
 
+            let location = arg.get_span();
             match key.as_str() {
                 "name" => {
                     let value = <Option<String> as TryFromMeta<V>>::try_from_meta(arg);
-                    builder.name.concat(value);
+                    builder.name.concat_arg(value, "name");
                 }
                 "default" => {
                     let value = <Option<CustomArg<Default>> as TryFromMeta<V>>::try_from_meta(arg);
-                    builder.default.concat(value);
+                    builder.default.concat_arg(value, "default");
+                }
+                "default_expr" => {
+                    let value = <Option<CustomArg<DefaultExpr>> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.default_expr.concat_arg(value, "default_expr");
+                }
+                "discriminant" => {
+                    let value = <bool as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.discriminant.concat_arg(value, "discriminant");
+                }
+                "untagged" => {
+                    let value = <bool as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.untagged.concat_arg(value, "untagged");
+                }
+                "positional" => {
+                    let value = <bool as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.positional.concat_arg(value, "positional");
+                }
+                "value" => {
+                    let value = <bool as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.value.concat_arg(value, "value");
+                }
+                "rename_all" => {
+                    let value = <Option<CustomArg<RenameAll>> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.rename_all.concat_arg(value, "rename_all");
+                }
+                "alias" => {
+                    let value = <Vec<String> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.alias.concat_arg(value, "alias");
+                }
+                "deprecated" => {
+                    let value = <Option<String> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.deprecated.concat_arg(value, "deprecated");
+                }
+                "deprecated_alias" => {
+                    let value = <Option<String> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.deprecated_alias.concat_arg(value, "deprecated_alias");
+                }
+                "skip" => {
+                    let value = <bool as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.skip.concat_arg(value, "skip");
+                }
+                "compute" => {
+                    let value = <Option<CustomArg<Computation>> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.compute.concat_arg(value, "compute");
+                }
+                "flatten" => {
+                    let value = <bool as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.flatten.concat_arg(value, "flatten");
+                }
+                "ignore_unknown" => {
+                    let value = <bool as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.ignore_unknown.concat_arg(value, "ignore_unknown");
+                }
+                "rest" => {
+                    let value = <bool as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.rest.concat_arg(value, "rest");
+                }
+                "validate" => {
+                    let value = <Option<CustomArg<FnPath>> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.validate.concat_arg(value, "validate");
+                }
+                "requires" => {
+                    let value = <Vec<String> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.requires.concat_arg(value, "requires");
+                }
+                "conflicts_with" => {
+                    let value = <Vec<String> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.conflicts_with.concat_arg(value, "conflicts_with");
+                }
+                "one_of" => {
+                    let value = <Vec<CustomArg<FieldGroup>> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.one_of.concat_arg(value, "one_of");
+                }
+                "at_least_one_of" => {
+                    let value = <Vec<CustomArg<FieldGroup>> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.at_least_one_of.concat_arg(value, "at_least_one_of");
+                }
+                "range" => {
+                    let value = <Option<CustomArg<ValueRange>> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.range.concat_arg(value, "range");
+                }
+                "len" => {
+                    let value = <Option<CustomArg<ValueRange>> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.len.concat_arg(value, "len");
+                }
+                "pattern" => {
+                    let value = <Option<CustomArg<Pattern>> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.pattern.concat_arg(value, "pattern");
+                }
+                "merge" => {
+                    let value = <Option<CustomArg<MergeStrategy>> as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.merge.concat_arg(value, "merge");
+                }
+                "non_finite" => {
+                    let value = <bool as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.non_finite.concat_arg(value, "non_finite");
+                }
+                "eval" => {
+                    let value = <bool as TryFromMeta<V>>::try_from_meta(arg);
+                    builder.eval.concat_arg(value, "eval");
                 }
 
-                _ => result.errors.push(Error::new(arg.get_span(), InvalidArg))
+                _ => {
+                    result.errors.push(Error::new(location, InvalidArg));
+                    continue;
+                }
             };
-
+            builder.args.push((key, location));
         }
         result.add_value(builder);
         result
@@ -588,7 +2117,7 @@ impl<V: SynVersion> TryFromMeta<V> for AttributeAttribute {
     fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
         let mut state = state;
 
-        if state.value.is_none() && state.found_with_errors() == false {
+        if state.value.is_none() && !state.found_with_errors() {
             return Ok(Self::default())
         }
 
@@ -608,17 +2137,188 @@ impl<V: SynVersion> TryFromMeta<V> for AttributeAttribute {
             state.errors.append(errors);
         }
 
+        let default_expr_location = builder.default_expr.location;
+        let mut maybe_default_expr = <Option<CustomArg<DefaultExpr>> as TryFromMeta<V>>::validate(builder.default_expr, "default_expr");
+        if let Err(ref mut errors) = maybe_default_expr {
+            state.errors.append(errors);
+        }
+
+        // an expression is stored as the default so it's expanded the same way
+        if let (Ok(default), Ok(Some(CustomArg(DefaultExpr(expr))))) = (&mut maybe_default, maybe_default_expr) {
+            match default {
+                Some(_) => state.errors.push(Error::new(default_expr_location, ConflictingArgs("default_expr", "default"))),
+                None => *default = Some(CustomArg(Default::Expr(expr)))
+            }
+        }
+
+        let mut maybe_discriminant = <bool as TryFromMeta<V>>::validate(builder.discriminant, "discriminant");
+        if let Err(ref mut errors) = maybe_discriminant {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_untagged = <bool as TryFromMeta<V>>::validate(builder.untagged, "untagged");
+        if let Err(ref mut errors) = maybe_untagged {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_positional = <bool as TryFromMeta<V>>::validate(builder.positional, "positional");
+        if let Err(ref mut errors) = maybe_positional {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_value = <bool as TryFromMeta<V>>::validate(builder.value, "value");
+        if let Err(ref mut errors) = maybe_value {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_rename_all = <Option<CustomArg<RenameAll>> as TryFromMeta<V>>::validate(builder.rename_all, "rename_all");
+        if let Err(ref mut errors) = maybe_rename_all {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_alias = <Vec<String> as TryFromMeta<V>>::validate(builder.alias, "alias");
+        if let Err(ref mut errors) = maybe_alias {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_deprecated = <Option<String> as TryFromMeta<V>>::validate(builder.deprecated, "deprecated");
+        if let Err(ref mut errors) = maybe_deprecated {
+            state.errors.append(errors);
+        }
+
+        let deprecated_alias_location = builder.deprecated_alias.location;
+        let mut maybe_deprecated_alias = <Option<String> as TryFromMeta<V>>::validate(builder.deprecated_alias, "deprecated_alias");
+        if let Err(ref mut errors) = maybe_deprecated_alias {
+            state.errors.append(errors);
+        }
+
+        if let (Ok(alias), Ok(Some(_))) = (&maybe_alias, &maybe_deprecated_alias) {
+            if alias.is_empty() {
+                state.errors.push(Error::new(deprecated_alias_location, RequiredBy { arg: "deprecated_alias", requires: "alias" }));
+            }
+        }
+
+        let mut maybe_skip = <bool as TryFromMeta<V>>::validate(builder.skip, "skip");
+        if let Err(ref mut errors) = maybe_skip {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_compute = <Option<CustomArg<Computation>> as TryFromMeta<V>>::validate(builder.compute, "compute");
+        if let Err(ref mut errors) = maybe_compute {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_flatten = <bool as TryFromMeta<V>>::validate(builder.flatten, "flatten");
+        if let Err(ref mut errors) = maybe_flatten {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_ignore_unknown = <bool as TryFromMeta<V>>::validate(builder.ignore_unknown, "ignore_unknown");
+        if let Err(ref mut errors) = maybe_ignore_unknown {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_rest = <bool as TryFromMeta<V>>::validate(builder.rest, "rest");
+        if let Err(ref mut errors) = maybe_rest {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_validate = <Option<CustomArg<FnPath>> as TryFromMeta<V>>::validate(builder.validate, "validate");
+        if let Err(ref mut errors) = maybe_validate {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_requires = <Vec<String> as TryFromMeta<V>>::validate(builder.requires, "requires");
+        if let Err(ref mut errors) = maybe_requires {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_conflicts_with = <Vec<String> as TryFromMeta<V>>::validate(builder.conflicts_with, "conflicts_with");
+        if let Err(ref mut errors) = maybe_conflicts_with {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_one_of = <Vec<CustomArg<FieldGroup>> as TryFromMeta<V>>::validate(builder.one_of, "one_of");
+        if let Err(ref mut errors) = maybe_one_of {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_at_least_one_of = <Vec<CustomArg<FieldGroup>> as TryFromMeta<V>>::validate(builder.at_least_one_of, "at_least_one_of");
+        if let Err(ref mut errors) = maybe_at_least_one_of {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_range = <Option<CustomArg<ValueRange>> as TryFromMeta<V>>::validate(builder.range, "range");
+        if let Err(ref mut errors) = maybe_range {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_len = <Option<CustomArg<ValueRange>> as TryFromMeta<V>>::validate(builder.len, "len");
+        if let Err(ref mut errors) = maybe_len {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_pattern = <Option<CustomArg<Pattern>> as TryFromMeta<V>>::validate(builder.pattern, "pattern");
+        if let Err(ref mut errors) = maybe_pattern {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_merge = <Option<CustomArg<MergeStrategy>> as TryFromMeta<V>>::validate(builder.merge, "merge");
+        if let Err(ref mut errors) = maybe_merge {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_non_finite = <bool as TryFromMeta<V>>::validate(builder.non_finite, "non_finite");
+        if let Err(ref mut errors) = maybe_non_finite {
+            state.errors.append(errors);
+        }
+
+        let mut maybe_eval = <bool as TryFromMeta<V>>::validate(builder.eval, "eval");
+        if let Err(ref mut errors) = maybe_eval {
+            state.errors.append(errors);
+        }
+
         match state.errors.len() {
-            0 => Ok(Self { name: maybe_name.expect("name failed"), default: maybe_default.expect("default failed") }),
+            0 => Ok(Self { 
+                name: maybe_name.expect("name failed"), 
+                default: maybe_default.expect("default failed"),
+                discriminant: maybe_discriminant.expect("discriminant failed"),
+                untagged: maybe_untagged.expect("untagged failed"),
+                positional: maybe_positional.expect("positional failed"),
+                value: maybe_value.expect("value failed"),
+                rename_all: maybe_rename_all.expect("rename_all failed"),
+                alias: maybe_alias.expect("alias failed"),
+                deprecated: maybe_deprecated.expect("deprecated failed"),
+                deprecated_alias: maybe_deprecated_alias.expect("deprecated_alias failed"),
+                skip: maybe_skip.expect("skip failed"),
+                compute: maybe_compute.expect("compute failed"),
+                flatten: maybe_flatten.expect("flatten failed"),
+                ignore_unknown: maybe_ignore_unknown.expect("ignore_unknown failed"),
+                rest: maybe_rest.expect("rest failed"),
+                validate: maybe_validate.expect("validate failed"),
+                requires: maybe_requires.expect("requires failed"),
+                conflicts_with: maybe_conflicts_with.expect("conflicts_with failed"),
+                one_of: maybe_one_of.expect("one_of failed"),
+                at_least_one_of: maybe_at_least_one_of.expect("at_least_one_of failed"),
+                range: maybe_range.expect("range failed"),
+                len: maybe_len.expect("len failed"),
+                pattern: maybe_pattern.expect("pattern failed"),
+                merge: maybe_merge.expect("merge failed"),
+                non_finite: maybe_non_finite.expect("non_finite failed"),
+                eval: maybe_eval.expect("eval failed"),
+                args: builder.args,
+            }),
             _ => Err(state.errors)
         }
     }
 }
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 enum Default {
     UseSelfDefault,
-    ChooseDefault(Ident)
+    ChooseDefault(syn_v2::Path),
+    Expr(syn_v2::Expr)
 }
 impl<V: SynVersion> CustomArgFromMeta<V> for Default {
     fn try_from_meta(meta: V::ArgMeta) -> Result<Self, ErrorMsg> {        
@@ -627,8 +2327,219 @@ impl<V: SynVersion> CustomArgFromMeta<V> for Default {
         
         match (maybe_bool, maybe_path) {
             (Some(is_default), _) if is_default => Ok(Self::UseSelfDefault),
-            (_, Some(path)) => Ok(Self::ChooseDefault(format_ident!("{path}"))),
+            (_, Some(path)) => 
+                match syn_v2::parse_str(&path) {
+                    Ok(path) => Ok(Self::ChooseDefault(path)),
+                    Err(_) => Err(InvalidValue(format!("'{path}' isn't a valid path")))
+                }
             _ => Err(InvalidType { expected: "boolean or path string" })
         }
     }
+}
+impl Default {
+    /// Creates an expression that returns the default value.
+    fn expand(&self, field_type: &syn_v2::Type) -> TokenStream {
+        match self {
+            Self::UseSelfDefault => quote!{ <#field_type as Default>::default() },
+            Self::ChooseDefault(path) => quote![ #path() ],
+            Self::Expr(expr) => quote![ #expr ]
+        }
+    }
+}
+
+/// An expression given as a string that's used as a default value EX) `"Vec::new()"`.
+#[derive(Debug)]
+struct DefaultExpr(syn_v2::Expr);
+impl<V: SynVersion> CustomArgFromMeta<V> for DefaultExpr {
+    fn try_from_meta(meta: V::ArgMeta) -> Result<Self, ErrorMsg> {
+        let expr = 
+            match V::deserialize_string(&meta) {
+                Some(expr) => expr,
+                None => return Err(InvalidType { expected: "expression string" })
+            };
+
+        match syn_v2::parse_str(&expr) {
+            Ok(expr) => Ok(Self(expr)),
+            Err(_) => Err(InvalidValue(format!("'{expr}' isn't a valid expression")))
+        }
+    }
+}
+
+/// A path to a function given as a string.
+#[derive(Debug)]
+struct FnPath(syn_v2::Path);
+impl<V: SynVersion> CustomArgFromMeta<V> for FnPath {
+    fn try_from_meta(meta: V::ArgMeta) -> Result<Self, ErrorMsg> {
+        let maybe_path = V::deserialize_string(&meta).and_then(|path| syn_v2::parse_str(&path).ok());
+
+        match maybe_path {
+            Some(path) => Ok(Self(path)),
+            None => Err(InvalidType { expected: "path string" })
+        }
+    }
+}
+
+/// A call to a function given as a string EX) `"full_name(first, last)"`. \
+/// Each input is the name of a field or the index of a tuple struct's field.
+#[derive(Debug)]
+struct Computation {
+    path: syn_v2::Path,
+    inputs: Vec<String>,
+    span: Span
+}
+impl<V: SynVersion> CustomArgFromMeta<V> for Computation {
+    fn try_from_meta(meta: V::ArgMeta) -> Result<Self, ErrorMsg> {
+        let call = 
+            match V::deserialize_string(&meta) {
+                Some(call) => call,
+                None => return Err(InvalidType { expected: "function call string EX) \"full_name(first, last)\"" })
+            };
+
+        let maybe_call: Option<syn_v2::ExprCall> = syn_v2::parse_str(&call).ok();
+        let maybe_computation = 
+            maybe_call.and_then(|call| {
+                let path = 
+                    match *call.func {
+                        syn_v2::Expr::Path(path) if path.qself.is_none() => path.path,
+                        _ => return None
+                    };
+                let inputs = 
+                    call.args.into_iter()
+                        .map(|input| match input {
+                            syn_v2::Expr::Path(path) => path.path.get_ident().map(|ident| ident.unraw().to_string()),
+                            syn_v2::Expr::Lit(syn_v2::ExprLit { lit: syn_v2::Lit::Int(index), .. }) => index.base10_parse::<usize>().ok().map(|index| index.to_string()),
+                            _ => None
+                        })
+                        .collect::<Option<Vec<String>>>()?;
+                Some(Self { path, inputs, span: meta.get_value_span() })
+            });
+
+        match maybe_computation {
+            Some(computation) => Ok(computation),
+            None => Err(InvalidValue(format!("'{call}' isn't a call whose inputs are field names")))
+        }
+    }
+}
+
+/// The names of a group of fields given as a list EX) `one_of(path, url)` and where each name is written.
+#[derive(Debug)]
+struct FieldGroup(Vec<(String, Span)>);
+impl<V: SynVersion> CustomArgFromMeta<V> for FieldGroup {
+    fn try_from_meta(meta: V::ArgMeta) -> Result<Self, ErrorMsg> {
+        let maybe_names: Option<Vec<(String, Span)>> = 
+            V::deserialize_list_args(&meta)
+                .and_then(|args| args.iter().map(|arg| Some((V::deserialize_ident(arg)?, arg.get_span()))).collect())
+                .filter(|names: &Vec<(String, Span)>| names.len() > 1);
+
+        match maybe_names {
+            Some(names) => Ok(Self(names)),
+            None => Err(InvalidType { expected: "list of at least two field names" })
+        }
+    }
+}
+
+/// A range given as a string EX) `"1..=64"` and the string to show in errors.
+#[derive(Debug)]
+struct ValueRange(syn_v2::ExprRange, String);
+impl<V: SynVersion> CustomArgFromMeta<V> for ValueRange {
+    fn try_from_meta(meta: V::ArgMeta) -> Result<Self, ErrorMsg> {
+        let maybe_range = V::deserialize_string(&meta).and_then(|range| Some((syn_v2::parse_str(&range).ok()?, range)));
+
+        match maybe_range {
+            Some((range, range_str)) => Ok(Self(range, range_str)),
+            None => Err(InvalidType { expected: "range string EX) \"1..=64\"" })
+        }
+    }
+}
+
+/// A regular expression given as a string. \
+/// It's compiled when the macro is derived so an invalid pattern is a compile error.
+#[derive(Debug)]
+struct Pattern(String);
+impl<V: SynVersion> CustomArgFromMeta<V> for Pattern {
+    fn try_from_meta(meta: V::ArgMeta) -> Result<Self, ErrorMsg> {
+        let pattern = 
+            match V::deserialize_string(&meta) {
+                Some(pattern) => pattern,
+                None => return Err(InvalidType { expected: "pattern string" })
+            };
+
+        #[cfg(feature = "pattern")]
+        {
+            match regex::Regex::new(&pattern) {
+                Ok(_) => Ok(Self(pattern)),
+                Err(error) => Err(InvalidValue(error.to_string()))
+            }
+        }
+        #[cfg(not(feature = "pattern"))]
+        {
+            let _ = pattern;
+            Err(InvalidValue(String::from("the 'pattern' feature must be enabled to use patterns")))
+        }
+    }
+}
+
+/// How a field combines an argument that's given more than once.
+#[derive(Debug, PartialEq)]
+enum MergeStrategy {
+    Error,
+    First,
+    Last,
+    Append,
+    Count,
+    Or
+}
+impl<V: SynVersion> CustomArgFromMeta<V> for MergeStrategy {
+    fn try_from_meta(meta: V::ArgMeta) -> Result<Self, ErrorMsg> {
+        let strategy = 
+            match V::deserialize_string(&meta) {
+                Some(strategy) => strategy,
+                None => return Err(InvalidType { expected: "merge strategy string" })
+            };
+
+        match strategy.as_str() {
+            "error" => Ok(Self::Error),
+            "first" => Ok(Self::First),
+            "last" => Ok(Self::Last),
+            "append" => Ok(Self::Append),
+            "count" => Ok(Self::Count),
+            "or" => Ok(Self::Or),
+            _ => Err(InvalidValue(format!("'{strategy}' isn't a merge strategy, expected one of error, first, last, append, count or or")))
+        }
+    }
+}
+impl MergeStrategy {
+    /// The method on `ArgResult` that combines the arguments.
+    fn method(&self) -> Ident {
+        match self {
+            Self::Error => format_ident!("merge_error"),
+            Self::First => format_ident!("merge_first"),
+            Self::Last => format_ident!("merge_last"),
+            Self::Append => format_ident!("merge_append"),
+            Self::Count => format_ident!("merge_count"),
+            Self::Or => format_ident!("merge_or")
+        }
+    }
+}
+
+/// The case that keys are converted to.
+#[derive(Debug)]
+struct RenameAll(Case);
+impl<V: SynVersion> CustomArgFromMeta<V> for RenameAll {
+    fn try_from_meta(meta: V::ArgMeta) -> Result<Self, ErrorMsg> {
+        let maybe_case = V::deserialize_string(&meta);
+
+        let case = 
+            match maybe_case.as_deref() {
+                Some("lowercase") => Case::Flat,
+                Some("UPPERCASE") => Case::UpperFlat,
+                Some("snake_case") => Case::Snake,
+                Some("SCREAMING_SNAKE_CASE") => Case::UpperSnake,
+                Some("camelCase") => Case::Camel,
+                Some("PascalCase") => Case::Pascal,
+                _ => return Err(InvalidType { expected: "one of \"lowercase\", \"UPPERCASE\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"camelCase\", \"PascalCase\"" })
+            };
+
+        Ok(Self(case))
+    }
 }
\ No newline at end of file
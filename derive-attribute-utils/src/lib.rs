@@ -16,5 +16,7 @@ pub use syn_2::Syn2;
 
 pub mod reexports {
     pub use proc_macro2;
+    #[cfg(feature = "pattern")]
+    pub use regex;
 }
 
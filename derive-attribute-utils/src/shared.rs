@@ -1,6 +1,7 @@
-use std::{str::FromStr, fmt::Display};
+use std::{str::FromStr, fmt::Display, cell::RefCell, any::Any, collections::{HashMap, BTreeMap}, ops::{RangeBounds, BitOrAssign, Deref}, num::*};
 
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream, Ident};
+use quote::quote;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -18,12 +19,66 @@ pub enum ErrorMsg {
     MissingArg(&'static str),
     #[error("Invalid Type: expected {expected}")]
     InvalidType{expected: &'static str},
-    #[error("Duplicate Argument")]
-    DuplicateArg,
+    #[error("Invalid Type: expected {}", expected.join(" or "))]
+    InvalidTypes{expected: Vec<&'static str>},
+    #[error("Duplicate Argument{}", key.as_ref().map(|key| format!(": '{key}' is already defined")).unwrap_or_default())]
+    DuplicateArg{key: Option<String>, first: Span},
     #[error("Invalid Argument")]
     InvalidArg,
+    #[error("Invalid Argument: positional arguments must come before named arguments")]
+    PositionalAfterNamed,
+    #[error("Too Many Arguments: expected at most {0} positional arguments")]
+    TooManyPositional(usize),
+    #[error("Invalid Rest Field: expected a collection of the selected Syn version's metadata")]
+    InvalidRestType,
+
+    #[error("Missing Variant: expected one of {0}")]
+    MissingVariant(&'static str),
+    #[error("Conflicting Variants: only one of {0} can be used")]
+    ConflictingVariants(&'static str),
+
+    #[error("Invalid Value: {0}")]
+    InvalidValue(String),
+
+    #[error("Missing Argument: '{arg}' requires '{requires}'")]
+    RequiredBy{arg: &'static str, requires: &'static str},
+    #[error("Conflicting Arguments: '{0}' can't be used with '{1}'")]
+    ConflictingArgs(&'static str, &'static str),
+    #[error("Missing Argument: expected one of {0}")]
+    MissingOneOf(&'static str),
+    #[error("Missing Argument: expected at least one of {0}")]
+    MissingAtLeastOneOf(&'static str),
+    #[error("Conflicting Arguments: only one of {0} can be used")]
+    ConflictingOneOf(&'static str),
+
+    #[error("Invalid Value: expected value in {expected}, found {found}")]
+    OutOfRange{expected: &'static str, found: String},
+    #[error("Invalid Length: expected length in {expected}, found {found}")]
+    InvalidLength{expected: &'static str, found: usize},
+    #[error("Invalid Value: expected value matching {pattern:?}, found {found:?}")]
+    PatternMismatch{pattern: &'static str, found: String},
+
+    #[error("Invalid Value: `{value}` is out of range for {expected}")]
+    NumberOverflow{value: String, expected: &'static str},
+    #[error("Invalid Value: expected {expected}, found `{value}` with the suffix {suffix}")]
+    SuffixMismatch{value: String, suffix: String, expected: &'static str},
+    #[error("Invalid Type: expected {expected}, found {found} `{value}`")]
+    WrongLiteralKind{value: String, found: &'static str, expected: &'static str},
+    #[error("Invalid Value: {expected} can't be zero")]
+    ZeroValue{expected: &'static str},
+    #[error("Invalid Value: expected value in {min}..={max}, found {found}")]
+    OutOfBounds{min: i128, max: i128, found: String},
 }
 use ErrorMsg::*;
+impl ErrorMsg {
+    /// A second message and the location it points at EX) where a duplicate argument was first defined.
+    pub fn note(&self) -> Option<(Span, &'static str)> {
+        match self {
+            DuplicateArg { first, .. } => Some((*first, "first defined here")),
+            _ => None
+        }
+    }
+}
 
 
 #[derive(Debug)]
@@ -40,6 +95,51 @@ impl Error {
     }
 }
 
+thread_local! {
+    static WARNINGS: RefCell<Vec<Warning>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A problem that doesn't stop an attribute from being deserialized such as a deprecated argument.
+#[derive(Debug, Clone)]
+pub struct Warning {
+    pub msg: String,
+    pub location: Span,
+}
+impl Warning {
+    pub fn new(location: Span, msg: impl Into<String>) -> Self {
+        Self {
+            location,
+            msg: msg.into()
+        }
+    }
+
+    /// Stores the warning so it's returned by the `Attribute::from_attrs_with_warnings` call that's deserializing it.
+    pub fn emit(self) {
+        WARNINGS.with(|warnings| warnings.borrow_mut().push(self));
+    }
+
+    /// Generates an item that makes the compiler show the warning at its location. \
+    /// Stable Rust can't emit warnings from a macro so the use of a deprecated item is used instead.
+    pub fn to_tokens(&self) -> TokenStream {
+        let msg = &self.msg;
+        let item_use = Ident::new("deprecated_argument", self.location);
+
+        quote!{
+            const _: () = {
+                #[deprecated(note = #msg)]
+                #[allow(non_camel_case_types)]
+                struct deprecated_argument;
+                let _ = #item_use;
+            };
+        }
+    }
+}
+
+/// Takes every warning emitted on this thread since the last time they were taken.
+fn take_warnings() -> Vec<Warning> {
+    WARNINGS.with(|warnings| warnings.take())
+}
+
 /// Combines an argument with a previously stored instance.
 pub trait Concat: Sized {
     /// Determines whether a duplicate is allowed or if an error should be thrown. \
@@ -52,11 +152,23 @@ pub trait Concat: Sized {
 }
 impl<T: Concat> Concat for ArgResult<T> {
     fn concat(&mut self, other: Self) {
+        self.concat_with_key(other, None);
+    }
+}
+impl<T: Concat> ArgResult<T> {
+    /// Combines an argument like `concat` and names its key if it's a duplicate.
+    pub fn concat_arg(&mut self, other: Self, key: &str) {
+        self.concat_with_key(other, Some(key));
+    }
+
+    fn concat_with_key(&mut self, other: Self, key: Option<&str>) {
         let mut other = other;
         if other.is_found() {
+            let first = self.location;
             self.location = other.location;
+            self.value_location = other.value_location;
             if self.is_found() && T::NO_DUPLICATES {
-                self.add_error(DuplicateArg);
+                self.add_error(DuplicateArg { key: key.map(String::from), first });
             }
         }
         if other.found_with_errors() {
@@ -65,13 +177,9 @@ impl<T: Concat> Concat for ArgResult<T> {
         if other.found_with_value() {    
             match self.value {
                 Some(ref mut value) => {
-                    match other.value {
-                        Some(other_value) => {
-                            value.concat(other_value);
-                        },
-                        None => {}
+                    if let Some(other_value) = other.value {
+                        value.concat(other_value);
                     }
-    
                 }
                 None => self.value = other.value
             }
@@ -88,6 +196,8 @@ pub struct ArgResult<T> {
     pub value: Option<T>,
     pub errors: Vec<Error>,
     pub location: Span,
+    /// The location of the value that was found, used by errors about the value itself EX) a value that's out of range.
+    pub value_location: Span,
 }
 impl<T> ArgResult<T> {
     pub fn new(location: Span) -> Self {
@@ -95,8 +205,13 @@ impl<T> ArgResult<T> {
             value: None,
             errors: vec![],
             location,
+            value_location: location,
         }
     }
+    /// Sets the location of the value, usually the Span of the argument's value.
+    pub fn with_value_location(self, value_location: Span) -> Self {
+        Self { value_location, ..self }
+    }
     /// Adds an error using the stored Span.
     pub fn add_error(&mut self, msg: ErrorMsg) {
         self.errors.push(Error::new(self.location, msg));
@@ -115,36 +230,134 @@ impl<T> ArgResult<T> {
         }
     }
     
-    pub fn is_found(&self) -> bool { self.errors.len() > 0 || self.value.is_some() }
-    pub fn found_with_errors(&self) -> bool { self.errors.len() > 0 }
+    pub fn is_found(&self) -> bool { !self.errors.is_empty() || self.value.is_some() }
+    pub fn found_with_errors(&self) -> bool { !self.errors.is_empty() }
     pub fn found_with_value(&self) -> bool { self.value.is_some() }
+
+    /// Combines an argument with `combine` if a value was already found. \
+    /// The location of the first argument is kept if `keep_first` is true.
+    fn merge_with(&mut self, other: Self, keep_first: bool, combine: impl FnOnce(&mut T, T)) {
+        let mut other = other;
+        if other.is_found() && !(keep_first && self.is_found()) {
+            self.location = other.location;
+            self.value_location = other.value_location;
+        }
+        self.errors.append(&mut other.errors);
+        if let Some(other_value) = other.value {
+            match self.value {
+                Some(ref mut value) => combine(value, other_value),
+                None => self.value = Some(other_value)
+            }
+        }
+    }
+
+    /// Returns an error if the argument was already found. Used by `#[attr(merge = "error")]`.
+    pub fn merge_error(&mut self, other: Self, key: &str) {
+        let is_duplicate = self.is_found() && other.is_found();
+        let first = self.location;
+        self.merge_with(other, false, |value, other| *value = other);
+        if is_duplicate {
+            self.add_error(DuplicateArg { key: Some(key.to_string()), first });
+        }
+    }
+    /// Keeps the first value that was found. Used by `#[attr(merge = "first")]`.
+    pub fn merge_first(&mut self, other: Self) {
+        self.merge_with(other, true, |_, _| {});
+    }
+    /// Keeps the last value that was found. Used by `#[attr(merge = "last")]`.
+    pub fn merge_last(&mut self, other: Self) {
+        self.merge_with(other, false, |value, other| *value = other);
+    }
+    /// Combines the values with the type's `Concat` implementation without checking for duplicates. \
+    /// Used by `#[attr(merge = "append")]`.
+    pub fn merge_append(&mut self, other: Self) where T: Concat {
+        self.merge_with(other, false, |value, other| value.concat(other));
+    }
+    /// Adds the values together. Used by `#[attr(merge = "count")]`.
+    pub fn merge_count(&mut self, other: Self) where T: Count {
+        self.merge_with(other, false, |value, other| value.add(other));
+    }
+    /// Combines the values with a logical or. Used by `#[attr(merge = "or")]`.
+    pub fn merge_or(&mut self, other: Self) where T: BitOrAssign {
+        self.merge_with(other, false, |value, other| *value |= other);
+    }
+}
+
+/// An integer that counts how many times an argument is given with `#[attr(merge = "count")]`. \
+/// A flag EX) `verbose` counts as one, an integer EX) `verbose = 2` is added as is.
+pub trait Count: Sized {
+    fn one() -> Self;
+    /// Adds a count, stopping at the type's maximum.
+    fn add(&mut self, other: Self);
+}
+
+/// Deserializes an argument of a field that's counted.
+pub fn count_occurrence<V, T>(meta: V::ArgMeta) -> ArgResult<T::InitialType> 
+where 
+    V: SynVersion, 
+    T: TryFromMeta<V, Metadata = V::ArgMeta>, 
+    T::InitialType: Count 
+{
+    match V::is_path(&meta) {
+        true => {
+            let mut result = ArgResult::new(meta.get_span());
+            result.add_value(Count::one());
+            result
+        }
+        false => T::try_from_meta(meta)
+    }
 }
 
 
 /// Represents a Syn version and how it can parse attribute data into values
 pub trait SynVersion: Sized {
     /// A type that represents an attribute.
-    type Attribute: GetSpan;
+    type Attribute: GetSpan + VecMeta;
 
-    /// Parses an attribute list into a vector of its elements as metadata.
+    /// Parses an attribute list into a vector of its elements as metadata. \
+    /// An attribute without a list EX) `#[name]` has no elements.
     fn deserialize_attr_args(attr: &Self::Attribute) -> Option<Vec<Self::ArgMeta>>;
-    /// Parses a nested list into a vector of its elements as metadata.
+    /// Gets the value of a name/value attribute EX) `#[name = value]` as metadata.
+    fn deserialize_attr_value(attr: &Self::Attribute) -> Option<Self::ArgMeta>;
+    /// Parses a nested list into a vector of its elements as metadata. A bare path is an empty list.
     fn deserialize_list_args(meta: &Self::ArgMeta) -> Option<Vec<Self::ArgMeta>>;
 
     /// Metadata that can be used to deserialize a value.
-    type ArgMeta: GetSpan;
+    type ArgMeta: GetSpan + VecMeta + Clone + 'static;
 
-    /// Gets the key from a key value pair as a string.
+    /// Gets the key of an argument as a string. Returns None if the argument is an unnamed value EX) `"value"`.
     fn deserialize_key(meta: &Self::ArgMeta) -> Option<String>;
+
+    /// Checks if an argument is a bare path EX) `value`. \
+    /// Bare paths are either booleans or positional arguments.
+    fn is_path(meta: &Self::ArgMeta) -> bool;
     
     /// Gets the name of an attribute list.
     fn deserialize_attr_key(meta: &Self::Attribute) -> Option<String>;
 
-    /// Attempts to get an integer from an argument. Returns None if the argument is a different type.
-    fn deserialize_integer<T>(meta: &Self::ArgMeta) -> Option<T> where T: FromStr, T::Err: Display;
+    /// Attempts to get an integer from an argument. The error describes why the argument isn't a valid `T`.
+    fn deserialize_integer<T: Numeric>(meta: &Self::ArgMeta) -> Result<T, NumberError> {
+        match Self::deserialize_number(meta) {
+            Some(number) => number.parse_integer(),
+            None => Err(NumberError::NotANumber)
+        }
+    }
+
+    /// Attempts to get a float from an argument. The error describes why the argument isn't a valid `T`. \
+    /// An integer without a suffix is converted to a float.
+    fn deserialize_float<T: Numeric>(meta: &Self::ArgMeta) -> Result<T, NumberError> {
+        match Self::deserialize_number(meta) {
+            Some(number) => number.parse_float(),
+            None => Err(NumberError::NotANumber)
+        }
+    }
+
+    /// Gets an integer or float literal without parsing it. Returns None if the argument is a different type.
+    fn deserialize_number(meta: &Self::ArgMeta) -> Option<NumberLit>;
 
-    /// Attempts to get a float from an argument. Returns None if the argument is a different type.
-    fn deserialize_float<T>(meta: &Self::ArgMeta) ->  Option<T> where T: FromStr, T::Err: Display;
+    /// Gets an expression made of numeric literals, parentheses & arithmetic operators EX) `4 * 1024`. \
+    /// Returns None if the argument is a different type.
+    fn deserialize_const_expr(meta: &Self::ArgMeta) -> Option<ConstExpr>;
 
     /// Attempts to get a string from an argument. Returns None if the argument is a different type.
     fn deserialize_string(meta: &Self::ArgMeta) -> Option<String>;
@@ -152,6 +365,18 @@ pub trait SynVersion: Sized {
     /// Attempts to get a boolean from an argument. Returns None if the argument is a different type.
     fn deserialize_bool(meta: &Self::ArgMeta) -> Option<bool>;
 
+    /// Attempts to get a character EX) `'x'` from an argument. Returns None if the argument is a different type.
+    fn deserialize_char(meta: &Self::ArgMeta) -> Option<char>;
+
+    /// Attempts to get a byte EX) `b'x'` from an argument. Returns None if the argument is a different type.
+    fn deserialize_byte(meta: &Self::ArgMeta) -> Option<u8>;
+
+    /// Attempts to get a byte string EX) `b"abc"` from an argument. Returns None if the argument is a different type.
+    fn deserialize_byte_str(meta: &Self::ArgMeta) -> Option<Vec<u8>>;
+
+    /// Attempts to get an identifier from an argument EX) `key = value` or a positional `value`. Returns None if the argument is a different type.
+    fn deserialize_ident(meta: &Self::ArgMeta) -> Option<String>;
+
     /// Attempts to get an array from an argument and returns a vector of its elements as metadata.
     fn deserialize_array(meta: &Self::ArgMeta) -> Option<Vec<Self::ArgMeta>>;
 
@@ -165,6 +390,15 @@ pub trait SynVersion: Sized {
 /// Gets the Span of Syn metadata
 pub trait GetSpan {
     fn get_span(&self) -> Span;
+    /// Gets the Span of an argument's value EX) `100` in `x = 100`. Defaults to the Span of the whole argument.
+    fn get_value_span(&self) -> Span { self.get_span() }
+}
+
+/// Metadata that a `Vec` can be deserialized from.
+pub trait VecMeta: Sized {
+    /// Splits the metadata into the metadata of each element. Returns None if it can't contain multiple elements. \
+    /// Repeated keys are concatenated, so metadata that isn't an array is usually a single element.
+    fn into_elements(self) -> Option<Vec<Self>>;
 }
 
 
@@ -200,6 +434,86 @@ pub fn required_validation<A, B: From<A>>(state: ArgResult<A>, arg_name: &'stati
     }
 }
 
+/// The error of a validator function given with `#[attr(validate = "...")]`. \
+/// A validator can return a message or one of this crate's errors.
+pub trait ValidationError {
+    fn into_error_msg(self) -> ErrorMsg;
+}
+impl ValidationError for ErrorMsg {
+    fn into_error_msg(self) -> ErrorMsg { self }
+}
+impl ValidationError for String {
+    fn into_error_msg(self) -> ErrorMsg { InvalidValue(self) }
+}
+impl ValidationError for &str {
+    fn into_error_msg(self) -> ErrorMsg { InvalidValue(self.to_string()) }
+}
+
+/// The location of each field of a struct, given to a container validator with `#[attr(validate = "...")]`.
+#[derive(Debug)]
+pub struct FieldSpans {
+    location: Span,
+    fields: Vec<(&'static str, Span)>,
+}
+impl FieldSpans {
+    pub fn new(location: Span, fields: Vec<(&'static str, Span)>) -> Self {
+        Self {
+            location,
+            fields
+        }
+    }
+
+    /// Gets the location of the argument that set a field, fields of a tuple struct are found by their index EX) `"0"`. \
+    /// Returns the location of the whole attribute or list if the field wasn't given.
+    pub fn get(&self, field: &str) -> Span {
+        self.fields.iter()
+            .find(|(name, _)| *name == field)
+            .map_or(self.location, |(_, location)| *location)
+    }
+
+    /// The location of the whole attribute or list.
+    pub fn location(&self) -> Span { self.location }
+}
+
+/// A type whose values can be checked with `#[attr(range = "...")]` or `#[attr(pattern = "...")]`. \
+/// Wrappers such as `Option` and `Vec` check each value they contain.
+pub trait ConstrainedValues {
+    type Value;
+    fn values(&self) -> Vec<&Self::Value>;
+}
+impl<T: ConstrainedValues> ConstrainedValues for Option<T> {
+    type Value = T::Value;
+    fn values(&self) -> Vec<&Self::Value> {
+        self.iter().flat_map(|value| value.values()).collect()
+    }
+}
+impl<T: ConstrainedValues> ConstrainedValues for Vec<T> {
+    type Value = T::Value;
+    fn values(&self) -> Vec<&Self::Value> {
+        self.iter().flat_map(|value| value.values()).collect()
+    }
+}
+
+/// A type whose length can be checked with `#[attr(len = "...")]`. \
+/// The length of a string is its number of characters, an `Option` is only checked if it's found.
+pub trait ConstrainedLength {
+    fn length(&self) -> Option<usize>;
+}
+impl ConstrainedLength for String {
+    fn length(&self) -> Option<usize> { Some(self.chars().count()) }
+}
+impl<T> ConstrainedLength for Vec<T> {
+    fn length(&self) -> Option<usize> { Some(self.len()) }
+}
+impl<T: ConstrainedLength> ConstrainedLength for Option<T> {
+    fn length(&self) -> Option<usize> { self.as_ref().and_then(|value| value.length()) }
+}
+
+/// Checks if a value is in a range. Lets the type of a range's literals be inferred from the value.
+pub fn in_range<T: PartialOrd, R: RangeBounds<T>>(range: R, value: &T) -> bool {
+    range.contains(value)
+}
+
 impl Concat for String {}
 impl<V: SynVersion> TryFromMeta<V> for String {
     type InitialType = Self;
@@ -251,6 +565,94 @@ impl<V: SynVersion> TryFromMeta<V> for bool {
     }
 }
 
+impl Concat for char {}
+impl<V: SynVersion> TryFromMeta<V> for char {
+    type InitialType = Self;
+
+    type Metadata = V::ArgMeta;
+    fn try_from_meta(meta: Self::Metadata) -> ArgResult<Self::InitialType> {
+        let mut result = ArgResult::new(meta.get_span());
+
+        match V::deserialize_char(&meta) {
+            Some(value) => result.add_value(value),
+            None => result.add_error(InvalidType { expected: "char" })
+        }
+
+        result
+    }
+
+    fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
+        required_validation(state, arg_name)
+    }
+}
+
+/// Lets a `u8` accept a byte literal EX) `b'x'` and a `Vec<u8>` accept a byte string EX) `b"abc"`. \
+/// Integers and arrays are still accepted EX) `0x7F` or `[b'a', 0]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ByteLit<T>(pub T);
+impl<T> Deref for ByteLit<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+impl<T: ConstrainedValues> ConstrainedValues for ByteLit<T> {
+    type Value = T::Value;
+    fn values(&self) -> Vec<&Self::Value> { self.0.values() }
+}
+impl<T: ConstrainedLength> ConstrainedLength for ByteLit<T> {
+    fn length(&self) -> Option<usize> { self.0.length() }
+}
+impl<V: SynVersion> TryFromMeta<V> for ByteLit<u8> {
+    type InitialType = u8;
+
+    type Metadata = V::ArgMeta;
+    fn try_from_meta(meta: Self::Metadata) -> ArgResult<Self::InitialType> {
+        let mut result = ArgResult::new(meta.get_span());
+
+        if let Some(value) = V::deserialize_byte(&meta) {
+            result.add_value(value);
+            return result;
+        }
+
+        match V::deserialize_integer::<u8>(&meta) {
+            Ok(value) => result.add_value(value),
+            Err(NumberError::NotANumber) => result.add_error(InvalidTypes { expected: vec!["byte", "u8"] }),
+            Err(error) => result.add_error(error.into_error_msg("u8"))
+        }
+
+        result
+    }
+
+    fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
+        required_validation(state, arg_name).map(Self)
+    }
+}
+impl<V: SynVersion> TryFromMeta<V> for ByteLit<Vec<u8>> {
+    type InitialType = Vec<ArgResult<u8>>;
+
+    type Metadata = V::ArgMeta;
+    fn try_from_meta(meta: Self::Metadata) -> ArgResult<Self::InitialType> {
+        match V::deserialize_byte_str(&meta) {
+            Some(bytes) => {
+                let location = meta.get_span();
+                let mut result = ArgResult::new(location);
+                let values = 
+                    bytes.into_iter()
+                        .map(|byte| ArgResult { value: Some(byte), errors: vec![], location, value_location: location })
+                        .collect();
+
+                result.add_value(values);
+                result
+            }
+            None => <Vec<ByteLit<u8>> as TryFromMeta<V>>::try_from_meta(meta)
+        }
+    }
+
+    fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
+        let bytes = <Vec<ByteLit<u8>> as TryFromMeta<V>>::validate(state, arg_name)?;
+        Ok(Self(bytes.into_iter().map(|byte| byte.0).collect()))
+    }
+}
+
 impl<T: Concat> Concat for Vec<T> {
     const NO_DUPLICATES: bool = false;
     fn concat(&mut self, other: Self) {
@@ -258,14 +660,17 @@ impl<T: Concat> Concat for Vec<T> {
         self.append(&mut other);
     }
 }
-impl<V: SynVersion, T: TryFromMeta<V, Metadata = V::ArgMeta>> TryFromMeta<V> for Vec<T> {
+impl<V: SynVersion, T: TryFromMeta<V>> TryFromMeta<V> for Vec<T> 
+where 
+    T::Metadata: GetSpan + VecMeta
+{
     type InitialType = Vec<ArgResult<T::InitialType>>;
-    type Metadata = V::ArgMeta;
+    type Metadata = T::Metadata;
 
     fn try_from_meta(meta: Self::Metadata) -> ArgResult<Self::InitialType> {
         let mut result = ArgResult::new(meta.get_span());
         let array = 
-            match V::deserialize_array(&meta) {
+            match meta.into_elements() {
                 Some(array) => array,
                 None => {
                     result.add_error(InvalidType { expected: "array" });
@@ -287,14 +692,11 @@ impl<V: SynVersion, T: TryFromMeta<V, Metadata = V::ArgMeta>> TryFromMeta<V> for
     fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
         let mut state = state;
 
+        // A missing vector is empty, wrap it in an option to tell them apart
         let values = 
             match state.found_with_errors() {
                 true => return Err(state.errors),
-                false if state.value.is_none() => {
-                    state.add_error(MissingArg(arg_name));
-                    return Err(state.errors);
-                },
-                false => state.value.unwrap()
+                false => state.value.take().unwrap_or_default()
             };
 
         let mut y = vec![];
@@ -350,28 +752,214 @@ where
     Self: TryFromMeta<V, Metadata = V::Attribute>
 {}
 
+impl<T: AttributeName> AttributeName for Vec<T> {
+    const NAME: &'static str = T::NAME;
+}
+
+/// Collects every instance of an attribute in the order they're found. \
+/// Each instance is validated on its own instead of being combined.
+impl<V: SynVersion, T: Attribute<V>> Attribute<V> for Vec<T>
+where 
+    Self: TryFromMeta<V, Metadata = V::Attribute>
+{}
+
 
 
 /// Represents a struct that can be deserialized from Syn attributes.
 pub trait Attribute<V: SynVersion>: AttributeName + TryFromMeta<V, Metadata = V::Attribute> {
-    /// Creates a deserialized attribute from a list of Syn attributes.
+    /// Creates a deserialized attribute from a list of Syn attributes. \
+    /// Warnings such as deprecated arguments are discarded, use `from_attrs_with_warnings` to show them.
     fn from_attrs(location: Span, attrs: Vec<V::Attribute>) -> Result<Self, Vec<V::Error>> {
+        Self::from_attrs_with_warnings(location, attrs).0
+    }
+
+    /// Creates a deserialized attribute from a list of Syn attributes along with the warnings found while deserializing it. \
+    /// The warnings are returned even if deserializing fails. Add the result of `Warning::to_tokens` to your macro's output for them to be shown.
+    fn from_attrs_with_warnings(location: Span, attrs: Vec<V::Attribute>) -> (Result<Self, Vec<V::Error>>, Vec<Warning>) {
+        // anything left over was emitted outside of this call and doesn't belong to these attributes
+        take_warnings();
+
         let mut result = ArgResult::new(location);
 
         for attr in attrs {
             let maybe_key = V::deserialize_attr_key(&attr);
             let found_attribute = matches!(maybe_key, Some(key) if key == Self::NAME);
-            if found_attribute == false { continue; }
+            if !found_attribute { continue; }
             
 
             let attr = Self::try_from_meta(attr);
-            result.concat(attr);
+            result.concat_arg(attr, Self::NAME);
         }
 
         let maybe_attr = <Self as TryFromMeta<V>>::validate(result, Self::NAME);
 
-        maybe_attr.map_err(|e| e.into_iter().map(|e| V::convert_error(e)).collect())
+        let maybe_attr = maybe_attr.map_err(|e| e.into_iter().map(|e| V::convert_error(e)).collect());
+        (maybe_attr, take_warnings())
+    }
+}
+
+
+/// A list whose keys can be matched by another list or attribute with `#[attr(flatten)]`. \
+/// Implemented by structs that derive `List`.
+pub trait Flatten: Sized {
+    /// Every key of the list including the keys of its own flattened fields.
+    const KEYS: &'static [&'static str];
+    /// Whether the list has a container default, used when none of its keys are given.
+    const HAS_DEFAULT: bool;
+
+    /// Creates a builder that the arguments of a parent are added to.
+    fn new_builder<V: SynVersion>(location: Span) -> <Self as TryFromMeta<V>>::InitialType
+    where 
+        Self: TryFromMeta<V>;
+
+    /// Adds an argument to the builder. The key must be one of `KEYS`.
+    fn add_arg<V: SynVersion>(builder: &mut <Self as TryFromMeta<V>>::InitialType, key: &str, arg: V::ArgMeta)
+    where 
+        Self: TryFromMeta<V>;
+}
+
+/// A collection of the arguments that don't match any key, filled by a field with `#[attr(rest)]`. \
+/// The arguments are kept as the metadata of the selected Syn version EX) `Vec<syn::Meta>` for Syn 2.
+pub trait RestArgs: Sized {
+    /// Collects the unmatched arguments with their keys in the order they're found.
+    fn from_args<V: SynVersion>(args: Vec<(String, V::ArgMeta)>) -> Result<Self, Vec<Error>>;
+}
+
+/// The metadata of an enabled Syn version, the only type a rest field can collect. \
+/// A rest field of any other type EX) `Vec<String>` fails to compile where the struct is derived.
+pub trait RestMeta: GetSpan + 'static {}
+
+impl<M: RestMeta> RestArgs for Vec<M> {
+    fn from_args<V: SynVersion>(args: Vec<(String, V::ArgMeta)>) -> Result<Self, Vec<Error>> {
+        let mut values = vec![];
+        let mut errors = vec![];
+
+        for (_, arg) in args {
+            match downcast_meta(arg) {
+                Ok(value) => values.push(value),
+                Err(error) => errors.push(error)
+            }
+        }
+
+        match errors.len() {
+            0 => Ok(values),
+            _ => Err(errors)
+        }
+    }
+}
+impl<M: RestMeta> RestArgs for HashMap<String, M> {
+    fn from_args<V: SynVersion>(args: Vec<(String, V::ArgMeta)>) -> Result<Self, Vec<Error>> {
+        collect_rest_map(args)
+    }
+}
+impl<M: RestMeta> RestArgs for BTreeMap<String, M> {
+    fn from_args<V: SynVersion>(args: Vec<(String, V::ArgMeta)>) -> Result<Self, Vec<Error>> {
+        collect_rest_map(args)
+    }
+}
+
+/// Collects unmatched arguments into a map, a key can only be found once.
+fn collect_rest_map<A, M, T>(args: Vec<(String, A)>) -> Result<T, Vec<Error>> 
+where 
+    A: GetSpan + 'static,
+    M: RestMeta,
+    T: Default + Extend<(String, M)>
+{
+    let mut keys: Vec<(String, Span)> = vec![];
+    let mut errors = vec![];
+    let mut map = T::default();
+
+    for (key, arg) in args {
+        if let Some((_, first)) = keys.iter().find(|(found_key, _)| *found_key == key) {
+            errors.push(Error::new(arg.get_span(), DuplicateArg { key: Some(key), first: *first }));
+            continue;
+        }
+        let location = arg.get_span();
+        match downcast_meta(arg) {
+            Ok(value) => {
+                keys.push((key.clone(), location));
+                map.extend([(key, value)]);
+            }
+            Err(error) => errors.push(error)
+        }
+    }
+
+    match errors.len() {
+        0 => Ok(map),
+        _ => Err(errors)
+    }
+}
+
+/// Converts the metadata of the Syn version being used to the type stored by a rest field. \
+/// The rest field can't be generic over the Syn version, `RestMeta` makes sure it stores metadata of an enabled version
+/// so this only fails if both versions are enabled and the field stores the other version's metadata.
+fn downcast_meta<A: GetSpan + 'static, M: RestMeta>(arg: A) -> Result<M, Error> {
+    let location = arg.get_span();
+    let arg: Box<dyn Any> = Box::new(arg);
+
+    match arg.downcast::<M>() {
+        Ok(value) => Ok(*value),
+        Err(_) => Err(Error::new(location, InvalidRestType))
+    }
+}
+
+/// Checks if a key is in a list of keys at compile time.
+pub const fn contains_key(keys: &[&str], key: &str) -> bool {
+    let mut i = 0;
+    while i < keys.len() {
+        if str_eq(keys[i], key) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Checks if two lists of keys share a key at compile time.
+pub const fn keys_overlap(a: &[&str], b: &[&str]) -> bool {
+    let mut i = 0;
+    while i < a.len() {
+        if contains_key(b, a[i]) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Joins groups of keys at compile time. `N` must be the total number of keys.
+pub const fn concat_keys<const N: usize>(groups: &[&[&'static str]]) -> [&'static str; N] {
+    let mut keys = [""; N];
+    let mut length = 0;
+
+    let mut i = 0;
+    while i < groups.len() {
+        let mut j = 0;
+        while j < groups[i].len() {
+            keys[length] = groups[i][j];
+            length += 1;
+            j += 1;
+        }
+        i += 1;
     }
+
+    keys
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
 }
 
 
@@ -394,7 +982,7 @@ impl<V: SynVersion, T: CustomArgFromMeta<V>> TryFromMeta<V> for CustomArg<T> {
         result.add_result(x);
         
         let v = result.value.map(|v| Self(v));
-        ArgResult { value: v, errors: result.errors, location: result.location }
+        ArgResult { value: v, errors: result.errors, location: result.location, value_location: result.value_location }
 
 
     }
@@ -415,17 +1003,46 @@ macro_rules! impl_integer {
     ($($type_name: ident), *) => {
         $(
             impl Concat for $type_name {}
+            impl Count for $type_name {
+                fn one() -> Self { 1 }
+                fn add(&mut self, other: Self) { *self = self.saturating_add(other) }
+            }
+            impl Numeric for $type_name {
+                const NAME: &'static str = stringify!($type_name);
+                fn is_finite(&self) -> bool { true }
+            }
+            impl BoundedInt for $type_name {
+                const MIN: i128 = $type_name::MIN as i128;
+                const MAX: i128 = 
+                    match $type_name::MAX as u128 > i128::MAX as u128 {
+                        true => i128::MAX,
+                        false => $type_name::MAX as i128
+                    };
+            }
+            impl ConstInt for $type_name {
+                fn is_zero(&self) -> bool { *self == 0 }
+                fn negate(self) -> Option<Self> { self.checked_neg() }
+                fn apply(self, op: BinaryOp, rhs: Self) -> Option<Self> {
+                    match op {
+                        BinaryOp::Add => self.checked_add(rhs),
+                        BinaryOp::Sub => self.checked_sub(rhs),
+                        BinaryOp::Mul => self.checked_mul(rhs),
+                        BinaryOp::Div => self.checked_div(rhs),
+                        BinaryOp::Rem => self.checked_rem(rhs),
+                        BinaryOp::Shl => u32::try_from(rhs).ok().and_then(|rhs| self.checked_shl(rhs)),
+                        BinaryOp::Shr => u32::try_from(rhs).ok().and_then(|rhs| self.checked_shr(rhs))
+                    }
+                }
+            }
             impl<V: SynVersion> TryFromMeta<V> for $type_name {
                 type InitialType = Self;
                 type Metadata = V::ArgMeta;
                 fn try_from_meta(meta: Self::Metadata) -> ArgResult<Self::InitialType> {
                     let mut result = ArgResult::new(meta.get_span());
 
-                    let maybe_int = V::deserialize_integer(&meta);
-
-                    match maybe_int {
-                        Some(value) => result.add_value(value),
-                        None => result.add_error(InvalidType { expected: stringify!($type_name) })
+                    match V::deserialize_integer::<$type_name>(&meta) {
+                        Ok(value) => result.add_value(value),
+                        Err(error) => result.add_error(error.into_error_msg(stringify!($type_name)))
                     }
 
                     result
@@ -439,21 +1056,272 @@ macro_rules! impl_integer {
         )*
     };
 }
+/// An integer or float literal as it was written.
+#[derive(Debug, Clone)]
+pub struct NumberLit {
+    /// The whole literal EX) `1.5e3f32`.
+    pub literal: String,
+    /// The literal in base 10 without its suffix EX) `1.5e3`.
+    pub digits: String,
+    /// The type suffix EX) `f32`, empty if there isn't one.
+    pub suffix: String,
+    pub is_float: bool,
+}
+
+impl NumberLit {
+    /// Adds a minus sign to the literal.
+    pub fn negate(self) -> Self {
+        Self {
+            literal: format!("-{}", self.literal),
+            digits: format!("-{}", self.digits),
+            ..self
+        }
+    }
+
+    /// Parses the literal as an integer type.
+    pub fn parse_integer<T: Numeric>(self) -> Result<T, NumberError> {
+        if self.is_float {
+            return Err(NumberError::WrongKind(self));
+        }
+        if !self.suffix.is_empty() && self.suffix != T::NAME {
+            return Err(NumberError::SuffixMismatch(self));
+        }
+
+        match self.digits.parse() {
+            Ok(value) => Ok(value),
+            Err(_) => Err(NumberError::Overflow(self))
+        }
+    }
+
+    /// Parses the literal as the bits of an integer type so a value that doesn't fit wraps around EX) `-1` is `255` for a `u8`. \
+    /// Only a value that doesn't fit in an `i128` or a `u128` is an error.
+    pub fn parse_wrapping<T: Numeric>(self) -> Result<u128, NumberError> {
+        if self.is_float {
+            return Err(NumberError::WrongKind(self));
+        }
+        if !self.suffix.is_empty() && self.suffix != T::NAME {
+            return Err(NumberError::SuffixMismatch(self));
+        }
+
+        // the bits of a negative value are the same in every integer type once they're truncated
+        match (self.digits.parse::<i128>(), self.digits.parse::<u128>()) {
+            (Ok(value), _) => Ok(value as u128),
+            (_, Ok(value)) => Ok(value),
+            _ => Err(NumberError::Overflow(self))
+        }
+    }
+
+    /// Parses the literal as a float type. An integer without a suffix is converted as well.
+    pub fn parse_float<T: Numeric>(self) -> Result<T, NumberError> {
+        if !self.suffix.is_empty() && self.suffix != T::NAME {
+            return match self.is_float {
+                true => Err(NumberError::SuffixMismatch(self)),
+                false => Err(NumberError::WrongKind(self))
+            };
+        }
+
+        // a literal that's too large for the type parses as infinity
+        match self.digits.parse::<T>() {
+            Ok(value) if value.is_finite() => Ok(value),
+            _ => Err(NumberError::Overflow(self))
+        }
+    }
+}
+
+/// A primitive number that can be parsed from a literal.
+pub trait Numeric: Sized + FromStr {
+    /// The name of the type which is also its literal suffix.
+    const NAME: &'static str;
+    fn is_finite(&self) -> bool;
+}
+
+/// Why a numeric argument couldn't be deserialized.
+#[derive(Debug)]
+pub enum NumberError {
+    /// The argument isn't a numeric literal.
+    NotANumber,
+    /// An integer was found instead of a float or a float instead of an integer.
+    WrongKind(NumberLit),
+    /// The literal has the suffix of a different type EX) `5u64` for a `u8`.
+    SuffixMismatch(NumberLit),
+    /// The value doesn't fit in the type EX) `300` for a `u8`.
+    Overflow(NumberLit),
+}
+impl NumberError {
+    /// Creates an error message for a type named `expected`.
+    pub fn into_error_msg(self, expected: &'static str) -> ErrorMsg {
+        match self {
+            NumberError::NotANumber => InvalidType { expected },
+            NumberError::WrongKind(number) => {
+                let found = if number.is_float { "float" } else { "integer" };
+                WrongLiteralKind { value: number.literal, found, expected }
+            }
+            NumberError::SuffixMismatch(number) => SuffixMismatch { value: number.literal, suffix: number.suffix, expected },
+            NumberError::Overflow(number) => NumberOverflow { value: number.literal, expected }
+        }
+    }
+}
+
+/// An operator of a constant expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Shl,
+    Shr
+}
+
+/// A numeric expression EX) `4 * (1 << 10)` that's evaluated when a field has `#[attr(eval)]`.
+#[derive(Debug, Clone)]
+pub struct ConstExpr {
+    pub kind: ConstExprKind,
+    /// The expression as it was written, used in errors.
+    pub text: String,
+    pub location: Span,
+}
+#[derive(Debug, Clone)]
+pub enum ConstExprKind {
+    Lit(NumberLit),
+    Neg(Box<ConstExpr>),
+    Binary(Box<ConstExpr>, BinaryOp, Box<ConstExpr>)
+}
+impl ConstExpr {
+    /// Evaluates the expression as an integer type. \
+    /// Returns an error at the part of the expression that overflows or divides by zero.
+    pub fn evaluate<T: ConstInt>(&self) -> Result<T, Error> {
+        let overflow = || Error::new(self.location, NumberOverflow { value: self.text.clone(), expected: T::NAME });
+
+        match &self.kind {
+            ConstExprKind::Lit(number) => parse_const_lit(number.clone(), self.location),
+            // a negative literal is parsed as a whole so the minimum of a signed type can be written EX) `-128i8`
+            ConstExprKind::Neg(expr) => 
+                match &expr.kind {
+                    ConstExprKind::Lit(number) => parse_const_lit(number.clone().negate(), self.location),
+                    _ => expr.evaluate::<T>()?.negate().ok_or_else(overflow)
+                },
+            ConstExprKind::Binary(left, op, right) => {
+                let left = left.evaluate::<T>()?;
+                let right = right.evaluate::<T>()?;
+
+                if matches!(op, BinaryOp::Div | BinaryOp::Rem) && right.is_zero() {
+                    return Err(Error::new(self.location, InvalidValue(format!("`{}` divides by zero", self.text))));
+                }
+                left.apply(*op, right).ok_or_else(overflow)
+            }
+        }
+    }
+}
+
+fn parse_const_lit<T: ConstInt>(number: NumberLit, location: Span) -> Result<T, Error> {
+    number.parse_integer().map_err(|error| Error::new(location, error.into_error_msg(T::NAME)))
+}
+
+/// An integer type that a constant expression can be evaluated as.
+pub trait ConstInt: Numeric {
+    fn is_zero(&self) -> bool;
+    /// Negates the value, returning None if it overflows.
+    fn negate(self) -> Option<Self>;
+    /// Applies an operator, returning None if it overflows.
+    fn apply(self, op: BinaryOp, rhs: Self) -> Option<Self>;
+}
+
+/// A float type that can be NaN or infinity.
+pub trait Float: Numeric {}
+
+/// Deserializes an argument of a float field that also accepts NaN and infinity. \
+/// Literals can't be NaN or infinity so they're written as strings EX) `"inf"`.
+pub fn non_finite_occurrence<V, T>(meta: V::ArgMeta) -> ArgResult<T::InitialType> 
+where 
+    V: SynVersion, 
+    T: TryFromMeta<V, Metadata = V::ArgMeta>, 
+    T::InitialType: Float 
+{
+    let maybe_non_finite = 
+        V::deserialize_string(&meta)
+            .and_then(|value| value.parse::<T::InitialType>().ok())
+            .filter(|value| !value.is_finite());
+
+    match maybe_non_finite {
+        Some(value) => {
+            let mut result = ArgResult::new(meta.get_span());
+            result.add_value(value);
+            result
+        }
+        None => T::try_from_meta(meta)
+    }
+}
+
+/// Deserializes an argument of a field that evaluates constant expressions.
+pub fn eval_occurrence<V, T>(meta: V::ArgMeta) -> ArgResult<T::InitialType> 
+where 
+    V: SynVersion, 
+    T: TryFromMeta<V, Metadata = V::ArgMeta>, 
+    T::InitialType: ConstInt 
+{
+    match V::deserialize_const_expr(&meta) {
+        Some(expr) => {
+            let mut result = ArgResult::new(meta.get_span());
+            match expr.evaluate() {
+                Ok(value) => result.add_value(value),
+                Err(error) => result.errors.push(error)
+            }
+            result
+        }
+        None => T::try_from_meta(meta)
+    }
+}
+
 macro_rules! impl_float {
     ($($type_name: ident), *) => {
         $(
             impl Concat for $type_name {}
+            impl Numeric for $type_name {
+                const NAME: &'static str = stringify!($type_name);
+                fn is_finite(&self) -> bool { $type_name::is_finite(*self) }
+            }
+            impl Float for $type_name {}
             impl<V: SynVersion> TryFromMeta<V> for $type_name {
                 type InitialType = Self;
                 type Metadata = V::ArgMeta;
                 fn try_from_meta(meta: Self::Metadata) -> ArgResult<Self::InitialType> {
                     let mut result = ArgResult::new(meta.get_span());
 
-                    let maybe_int = V::deserialize_integer(&meta);
+                    match V::deserialize_float(&meta) {
+                        Ok(value) => result.add_value(value),
+                        Err(error) => result.add_error(error.into_error_msg(stringify!($type_name)))
+                    }
+
+                    result
+                }
+
+                fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
+                    required_validation(state, arg_name)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_nonzero {
+    ($($type_name: ident => $integer: ident), *) => {
+        $(
+            impl Concat for $type_name {}
+            impl<V: SynVersion> TryFromMeta<V> for $type_name {
+                type InitialType = Self;
+                type Metadata = V::ArgMeta;
+                fn try_from_meta(meta: Self::Metadata) -> ArgResult<Self::InitialType> {
+                    let mut result = ArgResult::new(meta.get_span());
 
-                    match maybe_int {
-                        Some(value) => result.add_value(value),
-                        None => result.add_error(InvalidType { expected: stringify!($type_name) })
+                    match V::deserialize_integer::<$integer>(&meta) {
+                        Ok(value) => 
+                            match $type_name::new(value) {
+                                Some(value) => result.add_value(value),
+                                None => result.add_error(ZeroValue { expected: stringify!($type_name) })
+                            },
+                        Err(error) => result.add_error(error.into_error_msg(stringify!($type_name)))
                     }
 
                     result
@@ -467,5 +1335,115 @@ macro_rules! impl_float {
     };
 }
 
+macro_rules! impl_wrapping {
+    ($($type_name: ident), *) => {
+        $(
+            impl Concat for Wrapping<$type_name> {}
+            impl<V: SynVersion> TryFromMeta<V> for Wrapping<$type_name> {
+                type InitialType = Self;
+                type Metadata = V::ArgMeta;
+                fn try_from_meta(meta: Self::Metadata) -> ArgResult<Self::InitialType> {
+                    let mut result = ArgResult::new(meta.get_span());
+
+                    let maybe_bits = 
+                        match V::deserialize_number(&meta) {
+                            Some(number) => number.parse_wrapping::<$type_name>(),
+                            None => Err(NumberError::NotANumber)
+                        };
+
+                    match maybe_bits {
+                        Ok(bits) => result.add_value(Wrapping(bits as $type_name)),
+                        Err(error) => result.add_error(error.into_error_msg(stringify!($type_name)))
+                    }
+
+                    result
+                }
+
+                fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
+                    required_validation(state, arg_name)
+                }
+            }
+        )*
+    };
+}
+
+/// An integer type that can be used by `Bounded`.
+pub trait BoundedInt: Copy + TryInto<i128> {
+    /// The type's minimum as an `i128`.
+    const MIN: i128;
+    /// The type's maximum as an `i128`, a larger maximum is limited to `i128::MAX`.
+    const MAX: i128;
+}
+
+/// An integer that must be in `MIN..=MAX`. A value outside of the bounds is an error when it's deserialized. \
+/// EX) `Bounded<u8, 1, 10>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bounded<T, const MIN: i128, const MAX: i128>(T);
+impl<T: BoundedInt, const MIN: i128, const MAX: i128> Bounded<T, MIN, MAX> {
+    // evaluated wherever the type is used so bounds that are reversed or don't fit in `T` fail to compile
+    const VALID_BOUNDS: () = assert!(MIN <= MAX && MIN >= T::MIN && MAX <= T::MAX, "Bounded requires MIN <= MAX with both bounds in the range of the integer type");
+
+    /// Returns None if the value is outside of the bounds.
+    pub fn new(value: T) -> Option<Self> {
+        let () = Self::VALID_BOUNDS;
+
+        match value.try_into() {
+            Ok(bounded) if (MIN..=MAX).contains(&bounded) => Some(Self(value)),
+            _ => None
+        }
+    }
+}
+impl<T, const MIN: i128, const MAX: i128> Bounded<T, MIN, MAX> {
+    pub fn get(self) -> T { self.0 }
+}
+impl<T, const MIN: i128, const MAX: i128> Deref for Bounded<T, MIN, MAX> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target { &self.0 }
+}
+impl<T, const MIN: i128, const MAX: i128> Concat for Bounded<T, MIN, MAX> {}
+impl<V, T, const MIN: i128, const MAX: i128> TryFromMeta<V> for Bounded<T, MIN, MAX> 
+where
+    V: SynVersion,
+    T: TryFromMeta<V, InitialType = T> + BoundedInt + Display
+{
+    type InitialType = Self;
+    type Metadata = T::Metadata;
+
+    fn try_from_meta(meta: Self::Metadata) -> ArgResult<Self::InitialType> {
+        let ArgResult { value, errors, location, value_location } = T::try_from_meta(meta);
+        let mut result = ArgResult { value: None, errors, location, value_location };
+
+        if let Some(value) = value {
+            match Self::new(value) {
+                Some(value) => result.add_value(value),
+                None => result.add_error(OutOfBounds { min: MIN, max: MAX, found: value.to_string() })
+            }
+        }
+
+        result
+    }
+
+    fn validate(state: ArgResult<Self::InitialType>, arg_name: &'static str) -> Result<Self, Vec<Error>> {
+        required_validation(state, arg_name)
+    }
+}
+
+macro_rules! impl_constrained_values {
+    ($($type_name: ident), *) => {
+        $(
+            impl ConstrainedValues for $type_name {
+                type Value = Self;
+                fn values(&self) -> Vec<&Self::Value> { vec![self] }
+            }
+        )*
+    };
+}
+
 impl_integer!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
 impl_float!(f32, f64);
+impl_wrapping!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+impl_nonzero!(
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64, NonZeroU128 => u128,
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64, NonZeroI128 => i128
+);
+impl_constrained_values!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, char, String);
@@ -1,9 +1,6 @@
-use std::{str::FromStr, fmt::Display};
+use syn_v1::{NestedMeta, spanned::Spanned, Attribute, Meta, MetaList, MetaNameValue, Lit};
 
-use proc_macro2::Ident;
-use syn_v1::{NestedMeta, spanned::Spanned, Attribute, Meta, MetaList, MetaNameValue, Lit, parse_quote, ExprArray, Expr, PathSegment, PathArguments, Path, token::Eq};
-
-use crate::{shared::GetSpan, SynVersion};
+use crate::{shared::{GetSpan, VecMeta, RestMeta, NumberLit, ConstExpr, ConstExprKind}, SynVersion};
 
 /// Deserialization functions & types for Syn version 1
 pub struct Syn1;
@@ -15,6 +12,14 @@ impl SynVersion for Syn1 {
     fn deserialize_attr_args(attr: &Self::Attribute) -> Option<Vec<Self::ArgMeta>> {
         match attr.parse_meta() {
             Ok(Meta::List(MetaList { nested, .. })) => Some(nested.into_iter().collect()),
+            Ok(Meta::Path(_)) => Some(vec![]),
+            _ => None
+        }
+    }
+
+    fn deserialize_attr_value(attr: &Self::Attribute) -> Option<Self::ArgMeta> {
+        match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue { lit, .. })) => Some(NestedMeta::Lit(lit)),
             _ => None
         }
     }
@@ -22,6 +27,7 @@ impl SynVersion for Syn1 {
     fn deserialize_list_args(meta: &Self::ArgMeta) -> Option<Vec<Self::ArgMeta>> {
         match meta.clone() {
             NestedMeta::Meta(Meta::List(MetaList { nested, .. })) => Some(nested.into_iter().collect()),
+            NestedMeta::Meta(Meta::Path(_)) => Some(vec![]),
             _ => None
         }
     }
@@ -29,7 +35,8 @@ impl SynVersion for Syn1 {
     fn deserialize_bool(meta: &Self::ArgMeta) -> Option<bool> {
         match meta {
             NestedMeta::Meta(Meta::NameValue(MetaNameValue { lit: Lit::Bool(literal), .. })) => Some(literal.value()),
-            NestedMeta::Meta(Meta::Path(path)) => Some(true),
+            NestedMeta::Lit(Lit::Bool(literal)) => Some(literal.value()),
+            NestedMeta::Meta(Meta::Path(_)) => Some(true),
             _ => None
         }
     }
@@ -40,39 +47,89 @@ impl SynVersion for Syn1 {
 
     fn deserialize_key(meta: &Self::ArgMeta) -> Option<String> {
         match meta {
-            NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, .. })) => path.get_ident().map(|id| id.to_string()),
+            NestedMeta::Meta(meta) => meta.path().get_ident().map(|id| id.to_string()),
+            NestedMeta::Lit(_) => None
+        }
+    }
+
+    fn is_path(meta: &Self::ArgMeta) -> bool {
+        matches!(meta, NestedMeta::Meta(Meta::Path(_)))
+    }
+
+    fn deserialize_const_expr(meta: &Self::ArgMeta) -> Option<ConstExpr> {
+        // Syn 1 only allows literals as the value of a key/value pair, a negative literal EX) `-4` is still a literal
+        let number = Self::deserialize_number(meta)?;
+        Some(ConstExpr { text: number.literal.clone(), kind: ConstExprKind::Lit(number), location: meta.span() })
+    }
+
+    fn deserialize_number(meta: &Self::ArgMeta) -> Option<NumberLit> {
+        let lit = 
+            match meta {
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue { lit, .. })) => lit,
+                NestedMeta::Lit(lit) => lit,
+                _ => return None
+            };
+
+        match lit {
+            Lit::Int(literal) => Some(NumberLit { literal: literal.to_string(), digits: literal.base10_digits().to_string(), suffix: literal.suffix().to_string(), is_float: false }),
+            Lit::Float(literal) => Some(NumberLit { literal: literal.to_string(), digits: literal.base10_digits().to_string(), suffix: literal.suffix().to_string(), is_float: true }),
             _ => None
         }
     }
 
-    fn deserialize_integer<T>(meta: &Self::ArgMeta) -> Option<T> where T: std::str::FromStr, T::Err: std::fmt::Display {
+    fn deserialize_string(meta: &Self::ArgMeta) -> Option<String> {
+        match meta { 
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue { lit: Lit::Str(literal), .. })) => Some(literal.value()),
+            NestedMeta::Lit(Lit::Str(literal)) => Some(literal.value()),
+            _ => None
+        }
+    }
+
+    fn deserialize_char(meta: &Self::ArgMeta) -> Option<char> {
         match meta {
-            NestedMeta::Meta(Meta::NameValue(MetaNameValue { lit: Lit::Int(literal), .. })) => literal.base10_parse().map_or(None, Some),
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue { lit: Lit::Char(literal), .. })) => Some(literal.value()),
+            NestedMeta::Lit(Lit::Char(literal)) => Some(literal.value()),
             _ => None
         }
     }
 
-    fn deserialize_float<T>(meta: &Self::ArgMeta) ->  Option<T> where T: FromStr, T::Err: Display {
+    fn deserialize_byte(meta: &Self::ArgMeta) -> Option<u8> {
         match meta {
-            NestedMeta::Meta(Meta::NameValue(MetaNameValue { lit: Lit::Float(literal), .. })) => literal.base10_parse().map_or(None, Some),
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue { lit: Lit::Byte(literal), .. })) => Some(literal.value()),
+            NestedMeta::Lit(Lit::Byte(literal)) => Some(literal.value()),
             _ => None
         }
     }
 
-    fn deserialize_string(meta: &Self::ArgMeta) -> Option<String> {
-        match meta { 
-            NestedMeta::Meta(Meta::NameValue(MetaNameValue { lit: Lit::Str(literal), .. })) => Some(literal.value()),
+    fn deserialize_byte_str(meta: &Self::ArgMeta) -> Option<Vec<u8>> {
+        match meta {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue { lit: Lit::ByteStr(literal), .. })) => Some(literal.value()),
+            NestedMeta::Lit(Lit::ByteStr(literal)) => Some(literal.value()),
+            _ => None
+        }
+    }
+
+    fn deserialize_ident(meta: &Self::ArgMeta) -> Option<String> {
+        // Syn 1 only allows literals as the value of a key/value pair
+        match meta {
+            NestedMeta::Meta(Meta::Path(path)) => path.get_ident().map(|id| id.to_string()),
             _ => None
         }
     }
 
-    fn deserialize_array(meta: &Self::ArgMeta) -> Option<Vec<Self::ArgMeta>> {
-        unimplemented!("Parsing arrays/vectors is not implemented for Syn 1");
+    fn deserialize_array(_meta: &Self::ArgMeta) -> Option<Vec<Self::ArgMeta>> {
+        // Syn 1 can't parse arrays, repeated keys can be used instead
+        None
     }
 
     type Error = syn_v1::Error;
     fn convert_error(error: crate::Error) -> Self::Error {
-        syn_v1::Error::new(error.location, error.msg)
+        let note = error.msg.note();
+        let mut converted = syn_v1::Error::new(error.location, error.msg);
+        if let Some((location, note)) = note {
+            converted.combine(syn_v1::Error::new(location, note));
+        }
+        converted
     }
 }
 
@@ -86,4 +143,21 @@ impl GetSpan for NestedMeta {
     fn get_span(&self) -> proc_macro2::Span {
         self.span()
     }
+    fn get_value_span(&self) -> proc_macro2::Span {
+        match self {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue { lit, .. })) => lit.span(),
+            _ => self.span()
+        }
+    }
+}
+impl RestMeta for NestedMeta {}
+
+impl VecMeta for Attribute {
+    fn into_elements(self) -> Option<Vec<Self>> { Some(vec![self]) }
+}
+
+impl VecMeta for NestedMeta {
+    fn into_elements(self) -> Option<Vec<Self>> { 
+        Syn1::deserialize_array(&self).or_else(|| Some(vec![self]))
+    }
 }
\ No newline at end of file
@@ -1,10 +1,9 @@
 
-use std::{str::FromStr, fmt::Display};
-
 use proc_macro2::{Span, Ident};
-use syn_v2::{Attribute, Meta, MetaNameValue, Expr, ExprLit, Lit, punctuated::Punctuated, token::Eq, Token, spanned::Spanned, Path, ExprArray, PathSegment};
+use syn_v2::{Attribute, Meta, MetaNameValue, Expr, ExprLit, ExprPath, Lit, punctuated::Punctuated, token::Eq, Token, spanned::Spanned, Path, ExprArray, PathSegment, parse::ParseStream, ext::IdentExt, MetaList, MacroDelimiter, parenthesized, ExprUnary, UnOp, ExprBinary, BinOp, ExprParen, ExprGroup};
+use quote::ToTokens;
 
-use crate::{shared::{SynVersion, GetSpan}};
+use crate::{shared::{SynVersion, GetSpan, VecMeta, RestMeta, NumberLit, ConstExpr, ConstExprKind, BinaryOp}};
 
 /// Deserialization functions & types for Syn version 1
 pub struct Syn2;
@@ -15,30 +14,31 @@ impl SynVersion for Syn2 {
     type ArgMeta = Meta;
 
     fn deserialize_key(meta: &Self::ArgMeta) -> Option<String> {
-        meta.path().get_ident().map(|id| id.to_string())
+        meta.path().get_ident().map(|id| id.to_string()).filter(|key| key != UNNAMED_KEY)
+    }
+    fn is_path(meta: &Self::ArgMeta) -> bool {
+        matches!(meta, Meta::Path(_))
     }
     fn deserialize_attr_key(meta: &Self::Attribute) -> Option<String> {
         meta.path().get_ident().map(|id| id.to_string())
     }
 
-    fn deserialize_integer<T>(meta: &Self::ArgMeta) -> Option<T> 
-    where
-        T: FromStr,
-        T::Err: Display
-    {
+    fn deserialize_number(meta: &Self::ArgMeta) -> Option<NumberLit> {
         match meta {
-            Meta::NameValue(MetaNameValue { value: Expr::Lit(ExprLit { lit: Lit::Int(literal), .. }), .. }) => {
-                literal.base10_parse().map_or(None, Some)
-            },
+            Meta::NameValue(MetaNameValue { value: Expr::Lit(ExprLit { lit, .. }), .. }) => number_lit(lit),
+            // a negative number is a unary expression EX) `-4`
+            Meta::NameValue(MetaNameValue { value: Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. }), .. }) => 
+                match expr.as_ref() {
+                    Expr::Lit(ExprLit { lit, .. }) => number_lit(lit).map(NumberLit::negate),
+                    _ => None
+                },
             _ => None
         }
     }
-    
-    fn deserialize_float<T>(meta: &Self::ArgMeta) ->  Option<T> where T: FromStr, T::Err: Display {
+
+    fn deserialize_const_expr(meta: &Self::ArgMeta) -> Option<ConstExpr> {
         match meta {
-            Meta::NameValue(MetaNameValue { value: Expr::Lit(ExprLit { lit: Lit::Float(literal), .. }), .. }) => {
-                literal.base10_parse().map_or(None, Some)
-            },
+            Meta::NameValue(MetaNameValue { value, .. }) => const_expr(value),
             _ => None
         }
     }
@@ -57,50 +57,69 @@ impl SynVersion for Syn2 {
         }
     }
 
+    fn deserialize_char(meta: &Self::ArgMeta) -> Option<char> {
+        match meta {
+            Meta::NameValue(MetaNameValue { value: Expr::Lit(ExprLit { lit: Lit::Char(literal), .. }), .. }) => Some(literal.value()),
+            _ => None
+        }
+    }
+    fn deserialize_byte(meta: &Self::ArgMeta) -> Option<u8> {
+        match meta {
+            Meta::NameValue(MetaNameValue { value: Expr::Lit(ExprLit { lit: Lit::Byte(literal), .. }), .. }) => Some(literal.value()),
+            _ => None
+        }
+    }
+    fn deserialize_byte_str(meta: &Self::ArgMeta) -> Option<Vec<u8>> {
+        match meta {
+            Meta::NameValue(MetaNameValue { value: Expr::Lit(ExprLit { lit: Lit::ByteStr(literal), .. }), .. }) => Some(literal.value()),
+            _ => None
+        }
+    }
+
+    fn deserialize_ident(meta: &Self::ArgMeta) -> Option<String> {
+        match meta {
+            Meta::NameValue(MetaNameValue { value: Expr::Path(ExprPath { path, .. }), .. }) => path.get_ident().map(|id| id.to_string()),
+            Meta::Path(path) => path.get_ident().map(|id| id.to_string()),
+            _ => None
+        }
+    }
+
     fn deserialize_list_args(meta: &Self::ArgMeta) -> Option<Vec<Self::ArgMeta>> {
         match meta {
             Meta::List(list) => {
-                let x = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated);
+                let x = list.parse_args_with(|input: ParseStream| Punctuated::<Meta, Token![,]>::parse_terminated_with(input, parse_arg));
                 match x {
                     Ok(x) => Some(x.into_iter().collect()),
                     Err(_) => None
                 }
             },
+            Meta::Path(_) => Some(vec![]),
             _ => None
         }
     }
     fn deserialize_attr_args(attr: &Self::Attribute) -> Option<Vec<Self::ArgMeta>> {
-        let maybe_args = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated);
+        if let Meta::Path(_) = attr.meta {
+            return Some(vec![]);
+        }
+
+        let maybe_args = attr.parse_args_with(|input: ParseStream| Punctuated::<Meta, Token![,]>::parse_terminated_with(input, parse_arg));
         match maybe_args {
             Ok(args) => Some(args.into_iter().collect()),
             Err(_) => None
         }
     }
 
+    fn deserialize_attr_value(attr: &Self::Attribute) -> Option<Self::ArgMeta> {
+        match &attr.meta {
+            Meta::NameValue(MetaNameValue { value, .. }) => Some(unnamed_arg(value.clone())),
+            _ => None
+        }
+    }
+
     fn deserialize_array(meta: &Self::ArgMeta) -> Option<Vec<Self::ArgMeta>> {
         match meta {
             Meta::NameValue(MetaNameValue { value: Expr::Array(ExprArray { elems, .. }), .. }) => {
-                let list = 
-                    elems
-                        .into_iter()
-                        .map(|e| 
-                            Meta::NameValue(
-                                MetaNameValue { 
-                                    path: 
-                                        Path { 
-                                            leading_colon: None, 
-                                            segments: 
-                                                vec![PathSegment {
-                                                    ident: Ident::new("_", e.span()), 
-                                                    arguments: syn_v2::PathArguments::None 
-                                                }]
-                                                .into_iter().collect() 
-                                        }, 
-                                    eq_token: Eq { spans: [meta.span()] }, 
-                                    value: e.clone() 
-                                }
-                            )
-                        ).collect();
+                let list = elems.iter().map(|e| unnamed_arg(e.clone())).collect();
                 Some(list)
             }
             _ => None
@@ -109,8 +128,99 @@ impl SynVersion for Syn2 {
 
     type Error = syn_v2::Error;
     fn convert_error(error: crate::shared::Error) -> Self::Error {
-        syn_v2::Error::new(error.location, error.msg)
+        let note = error.msg.note();
+        let mut converted = syn_v2::Error::new(error.location, error.msg);
+        if let Some((location, note)) = note {
+            converted.combine(syn_v2::Error::new(location, note));
+        }
+        converted
+    }
+}
+
+/// Gets an integer or float literal as it was written.
+fn number_lit(lit: &Lit) -> Option<NumberLit> {
+    match lit {
+        Lit::Int(literal) => Some(NumberLit { literal: literal.to_string(), digits: literal.base10_digits().to_string(), suffix: literal.suffix().to_string(), is_float: false }),
+        Lit::Float(literal) => Some(NumberLit { literal: literal.to_string(), digits: literal.base10_digits().to_string(), suffix: literal.suffix().to_string(), is_float: true }),
+        _ => None
+    }
+}
+
+/// Converts an expression made of numeric literals, parentheses & arithmetic operators.
+fn const_expr(expr: &Expr) -> Option<ConstExpr> {
+    let kind = 
+        match expr {
+            Expr::Lit(ExprLit { lit, .. }) => ConstExprKind::Lit(number_lit(lit)?),
+            Expr::Paren(ExprParen { expr, .. }) | Expr::Group(ExprGroup { expr, .. }) => return const_expr(expr),
+            Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. }) => ConstExprKind::Neg(Box::new(const_expr(expr)?)),
+            Expr::Binary(ExprBinary { left, op, right, .. }) => {
+                let op = 
+                    match op {
+                        BinOp::Add(_) => BinaryOp::Add,
+                        BinOp::Sub(_) => BinaryOp::Sub,
+                        BinOp::Mul(_) => BinaryOp::Mul,
+                        BinOp::Div(_) => BinaryOp::Div,
+                        BinOp::Rem(_) => BinaryOp::Rem,
+                        BinOp::Shl(_) => BinaryOp::Shl,
+                        BinOp::Shr(_) => BinaryOp::Shr,
+                        _ => return None
+                    };
+                ConstExprKind::Binary(Box::new(const_expr(left)?), op, Box::new(const_expr(right)?))
+            }
+            _ => return None
+        };
+
+    Some(ConstExpr { kind, text: expr.to_token_stream().to_string(), location: expr.span() })
+}
+
+/// The key given to values without one such as positional arguments & array elements.
+const UNNAMED_KEY: &str = "_";
+
+/// Wraps a value without a key in a key/value pair so it can be deserialized like any other argument.
+fn unnamed_arg(value: Expr) -> Meta {
+    Meta::NameValue(
+        MetaNameValue { 
+            path: 
+                Path { 
+                    leading_colon: None, 
+                    segments: 
+                        vec![PathSegment {
+                            ident: Ident::new(UNNAMED_KEY, value.span()), 
+                            arguments: syn_v2::PathArguments::None 
+                        }]
+                        .into_iter().collect() 
+                }, 
+            eq_token: Eq { spans: [value.span()] }, 
+            value
+        }
+    )
+}
+
+/// Parses an argument of a list. Unnamed literals are parsed as positional arguments.
+fn parse_arg(input: ParseStream) -> syn_v2::Result<Meta> {
+    let is_keyword = input.peek(Ident::peek_any) && !input.peek(syn_v2::Ident);
+    match input.peek(Lit) {
+        true => Ok(unnamed_arg(Expr::Lit(input.parse()?))),
+        false if is_keyword => parse_keyword_arg(input),
+        false => input.parse()
+    }
+}
+
+/// Parses an argument whose key is a keyword EX) `type = "value"` since Syn only allows identifiers.
+fn parse_keyword_arg(input: ParseStream) -> syn_v2::Result<Meta> {
+    let path = Path::from(Ident::parse_any(input)?);
+
+    if input.peek(Token![=]) {
+        let meta = MetaNameValue { path, eq_token: input.parse()?, value: input.parse()? };
+        return Ok(Meta::NameValue(meta));
+    }
+    if input.peek(syn_v2::token::Paren) {
+        let content;
+        let delimiter = MacroDelimiter::Paren(parenthesized!(content in input));
+        let meta = MetaList { path, delimiter, tokens: content.parse()? };
+        return Ok(Meta::List(meta));
     }
+    Ok(Meta::Path(path))
 }
 
 impl GetSpan for Attribute {
@@ -119,5 +229,22 @@ impl GetSpan for Attribute {
 
 impl GetSpan for Meta {
     fn get_span(&self) -> Span { self.path().span() }
+    fn get_value_span(&self) -> Span {
+        match self {
+            Meta::NameValue(MetaNameValue { value, .. }) => value.span(),
+            _ => self.span()
+        }
+    }
+}
+impl RestMeta for Meta {}
+
+impl VecMeta for Attribute {
+    fn into_elements(self) -> Option<Vec<Self>> { Some(vec![self]) }
+}
+
+impl VecMeta for Meta {
+    fn into_elements(self) -> Option<Vec<Self>> { 
+        Syn2::deserialize_array(&self).or_else(|| Some(vec![self]))
+    }
 }
 
@@ -31,12 +31,33 @@
 //! <br/>
 //! ` #[some_attr(list(key_a = "value"))] ` <br/>
 //! ` #[some_attr(list(key_b = 123))] `
+//! #### **Repeated Keys**
+//! ` #[some_attr(include = "a", include = "b")] ` *is the same as* ` #[some_attr(include = ["a", "b"])] ` <br/>
+//! ` #[some_attr(header(name = "x"), header(name = "y"))] ` *can be deserialized as a* `Vec` *of lists* <br/>
+//! A missing `Vec` is empty, wrap it in an `Option` to tell a missing argument apart from an empty one.
 //!
+//! Note: Syn 1 only supports repeated keys
+//! #### **Positional Arguments**
+//! ` #[route("/users", method = "GET")] ` <br/>
+//! Fields of a tuple struct and fields with `#[attr(positional)]` can be given in order without a key before any named arguments.
+//! #### **Markers & Values**
+//! ` #[some_attr] ` *is the same as* ` #[some_attr()] ` *which lets unit structs be used as markers* <br/>
+//! ` #[some_attr = "value"] ` *sets the field marked with* `#[attr(value)]`
+//!
+//!
+//! ## Repeated Attributes
+//! Wrapping an attribute in a `Vec` collects every instance of it in order. </br>
+//! ` #[route("/a")] #[route("/b")] ` *can be deserialized as* `Vec<Route>` *with each instance validated on its own.*
 //!
 //! ## Multiple Errors
 //! Most macros will only return one attribute error at a time. </br>
 //! This crate's macros can return multiple errors at once resulting in a better developer experience.
 //!
+//! ## Warnings
+//! Deprecated arguments produce warnings instead of errors. </br>
+//! They're returned alongside the result by `from_attrs_with_warnings`, even if deserializing fails. </br>
+//! Each one can be turned into tokens with `Warning::to_tokens`, which must be added to your macro's output for the warning to be shown.
+//!
 //! ## Custom Deserialization
 //! Any type that implements `TryFromMeta` can be used as a valid attribute type. </br>
 //! Although Its recommended that you use `CustomArgFromMeta` instead in order to simplify the implementation.
@@ -44,8 +65,37 @@
 //! See [example](#custom-deserialization-1)
 //! <br/>
 //!
+//! ## Enum Values
+//! Unit enums can derive `AttrValue` to be deserialized from a string or an identifier. </br>
+//! ` #[some_attr(mode = "fast")] ` *can also be written as* ` #[some_attr(mode = fast)] ` <br/>
+//! Variants are matched by their name in snake_case unless they're renamed with `#[attr(name = "...")]`.
+//!
+//! Note: Syn 1 only supports the string form
+//!
+//! Enums with `#[attr(untagged)]` wrap a type in each variant instead and try to deserialize them in order. </br>
+//! ` #[some_attr(default)] ` *and* ` #[some_attr(default = "path")] ` *could both be deserialized into* `enum Default { Flag(bool), Path(String) }`
+//!
+//! ## Enum Lists
+//! Enums can derive `List` so the key of an argument chooses the variant. </br>
+//! ` #[some_attr(source(file(path = "a")))] ` *or* ` #[some_attr(source(env(var = "X")))] ` <br/>
+//! Unit variants are written like booleans, newtype variants are deserialized as the wrapped type and struct variants are deserialized like a list.
+//! Exactly one variant must be given.
+//!
+//! ## Bounded Integers
+//! The non-zero integers from `std::num` EX) `NonZeroU8` can be used as arguments, a zero is an error. </br>
+//! `Wrapping<T>` wraps a value that doesn't fit around the integer's bounds EX) `-1` is `255` for a `Wrapping<u8>`. </br>
+//! `Bounded<T, MIN, MAX>` only accepts an integer in `MIN..=MAX` and its value can be read with `get` or through `Deref`. Bounds that are reversed or don't fit in `T` fail to build. <br/>
+//! ` #[some_attr(retries = 3)] ` *can be deserialized as* `Bounded<u8, 1, 10>` *while* ` #[some_attr(retries = 0)] ` *is an error*
+//!
+//! ## Characters & Bytes
+//! A `char` is deserialized from a character literal EX) ` #[some_attr(delimiter = ',')] ` <br/>
+//! `ByteLit<u8>` accepts a byte literal EX) `b'\n'` and `ByteLit<Vec<u8>>` accepts a byte string EX) `b"\x7FELF"`. </br>
+//! Both still accept integers and arrays of bytes EX) `10` or `[b'-', 0]`, and their value can be read through `Deref`. </br>
+//! Custom types can read these literals with `SynVersion::deserialize_char`, `deserialize_byte` and `deserialize_byte_str`.
+//!
 //! ## Attr Arguments
-//! The `#[attr()]` attribute can be added to the attribute struct or its fields to add additional options.
+//! The `#[attr()]` attribute can be added to the attribute struct or its fields to add additional options. \
+//! An option used where it has no effect EX) `requires` on a struct, `untagged` on a field or `validate` on a skipped field is a compile error.
 //!
 //! **The full list of arguments are:**
 //!
@@ -54,11 +104,103 @@
 //! **default [<span style = "color: lightblue">bool/str</span>]** - Uses a default value if the argument isn't found.
 //! <span style = "font-size: 10px"> </span><br/>
 //! If its a boolean, the type's implementation of Default::default will be used. \
-//! If its a string, it must be a path to a function that returns the type. 
+//! If its a string, it must be a path to a function that returns the type EX) `"crate::defaults::port"`. \
+//! On a `List` struct, the default is used when the list isn't found. A list without arguments EX) `#[my_attr(list)]` uses the default of each field.
+//!
+//! **default_expr [<span style = "color: lightblue">str</span>]** - Uses an expression as the default value if the argument isn't found EX) `"Vec::new()"`.
+//! <span style = "font-size: 10px"> </span><br/>
+//! Can't be used with `default`.
+//!
+//! **discriminant [<span style = "color: lightblue">bool</span>]** - Allows an `AttrValue` enum to also be deserialized from its integer discriminants.
+//!
+//! **untagged [<span style = "color: lightblue">bool</span>]** - Makes an `AttrValue` enum try each variant's type in order.
+//!
+//! **positional [<span style = "color: lightblue">bool</span>]** - Allows the field to be given as a positional argument.
+//!
+//! **value [<span style = "color: lightblue">bool</span>]** - Allows an attribute to be written as `#[name = value]` to set this field.
+//!
+//! **alias [<span style = "color: lightblue">str</span>]** - Allows the field to also be found under another key. Can be repeated.
+//!
+//! **deprecated [<span style = "color: lightblue">str</span>]** - Emits a warning with the given note when the field is used under its key or any of its aliases.
+//!
+//! **deprecated_alias [<span style = "color: lightblue">str</span>]** - Emits a warning with the given note when one of the field's aliases is used. \
+//! The field's own key isn't deprecated. Requires `alias`.
+//!
+//! **skip [<span style = "color: lightblue">bool</span>]** - Never deserializes the field. Its value comes from `default` or the type's implementation of Default::default.
+//!
+//! **compute [<span style = "color: lightblue">str</span>]** - Skips the field and sets it with a function once every other field is valid.
+//! <span style = "font-size: 10px"> </span><br/>
+//! It's written as a call that names its inputs EX) `compute = "qualified_name(name, schema)"` and each input is given by reference. \
+//! An input can be any field that isn't computed. A tuple struct's fields are named by their index.
+//!
+//! **flatten [<span style = "color: lightblue">bool</span>]** - Matches the keys of a `List` struct as if they were the keys of the parent.
+//! <span style = "font-size: 10px"> </span><br/>
+//! This lets groups of arguments be shared between attributes. \
+//! A key that's used by both the parent and a flattened field causes a compile error. \
+//! The list's container `default` is used when none of its keys are given.
+//!
+//! **validate [<span style = "color: lightblue">str</span>]** - Checks the field with a function once it's deserialized.
+//! <span style = "font-size: 10px"> </span><br/>
+//! The function must be a path that takes a reference to the field and returns `Result<(), E>` where `E` is a `String` or an `ErrorMsg`. \
+//! The error is shown at the argument that set the field.
+//! <span style = "font-size: 10px"> </span><br/>
+//! On a struct, the function is given a reference to the struct and its `FieldSpans` once every field is valid and returns `Result<(), Vec<Error>>`. \
+//! This allows rules between fields EX) `min` must be less than `max`.
+//!
+//! **range [<span style = "color: lightblue">str</span>]** - Checks that the field's value is in a range EX) `"1..=64"`.
+//! <span style = "font-size: 10px"> </span><br/>
+//! Works on numbers, characters and strings, lists and options check each value they contain.
+//!
+//! **len [<span style = "color: lightblue">str</span>]** - Checks that the field's length is in a range EX) `"1.."`.
+//! <span style = "font-size: 10px"> </span><br/>
+//! Works on strings and lists, the length of a string is its number of characters.
+//!
+//! **pattern [<span style = "color: lightblue">str</span>]** - Checks that the field's strings match a regular expression EX) `"^[a-z_]+$"`.
+//! <span style = "font-size: 10px"> </span><br/>
+//! Requires the `pattern` feature, the pattern is compiled when the macro is derived so an invalid pattern causes a compile error.
+//!
+//! **merge [<span style = "color: lightblue">str</span>]** - Chooses how an argument that's given more than once is combined.
+//! <span style = "font-size: 10px"> </span><br/>
+//! `"error"` returns an error, `"first"` keeps the first value and `"last"` keeps the last value. \
+//! `"append"` combines lists without an error, `"or"` sets a boolean if any argument is true. \
+//! `"count"` adds up an integer where a flag EX) `verbose` counts as one, the field is zero if it isn't found.
+//!
+//! **non_finite [<span style = "color: lightblue">bool</span>]** - Allows a float to be NaN or infinity, written as the strings `"NaN"`, `"inf"` or `"-inf"`.
+//! <span style = "font-size: 10px"> </span><br/>
+//! Literals are always finite so a float only accepts literals without this option. Can't be used with `eval`.
+//!
+//! **eval [<span style = "color: lightblue">bool</span>]** - Evaluates an integer expression EX) `size = 4 * 1024`.
+//! <span style = "font-size: 10px"> </span><br/>
+//! Supports `+ - * / % << >>`, negation & parentheses, overflow and division by zero are errors. \
+//! Syn 1 only allows literals as values.
+//!
+//! **requires [<span style = "color: lightblue">str</span>]** - Returns an error if the field is given without another field. Can be repeated.
+//!
+//! **conflicts_with [<span style = "color: lightblue">str</span>]** - Returns an error if the field is given with another field. Can be repeated.
+//! <span style = "font-size: 10px"> </span><br/>
+//! The error is shown at both arguments.
+//!
+//! **one_of [<span style = "color: lightblue">list</span>]** - Requires exactly one field of a group to be given EX) `#[attr(one_of(path, url))]`. Can be repeated.
+//!
+//! **at_least_one_of [<span style = "color: lightblue">list</span>]** - Requires at least one field of a group to be given EX) `#[attr(at_least_one_of(read, write))]`. Can be repeated.
+//!
+//! **ignore_unknown [<span style = "color: lightblue">bool</span>]** - Ignores keys that don't belong to any field instead of returning an error.
+//! <span style = "font-size: 10px"> </span><br/>
+//! This lets two macros read their own keys from the same attribute.
+//!
+//! **rest [<span style = "color: lightblue">bool</span>]** - Collects every argument whose key doesn't belong to another field.
+//! <span style = "font-size: 10px"> </span><br/>
+//! The field must be a `Vec` or a map keyed by `String` of the selected Syn version's metadata EX) `Vec<syn::Meta>` or `BTreeMap<String, syn::Meta>` for Syn 2. Any other type fails to compile.
+//!
+//! **rename_all [<span style = "color: lightblue">str</span>]** - Converts the keys of every field or variant to a case.
+//! <span style = "font-size: 10px"> </span><br/>
+//! The case can be `lowercase`, `UPPERCASE`, `snake_case`, `SCREAMING_SNAKE_CASE`, `camelCase` or `PascalCase`. \
+//! Raw identifiers like `r#type` are always written without their prefix EX) `type = "..."`.
 //!
 //! # Usage
 //! Our attribute type is declared in a procedural macro crate:
 //! ```rust
+//! # use derive_attribute::{Attribute, List};
 //! #[derive(Attribute)]
 //! #[attr(name = "my_attr")] // We set the attribute name to 'my_attr'
 //! struct MyAttribute {      // Note: The attribute name will be the struct name in snake_case by default
@@ -70,14 +212,32 @@
 //!     is_selected: bool,
 //! }
 //!     #[derive(List)]
-//!     pub struct NestedList {
+//!     struct NestedList {
 //!         num: Option<u8>
 //!     }
 //! ```
 //! It can then be used to parse the following attribute using the from_attrs method:
 //!
 //! ```rust
+//! # use derive_attribute::{Attribute, List};
+//! # #[derive(Attribute)]
+//! # #[attr(name = "my_attr")]
+//! # struct MyAttribute {
+//! #     name: String,
+//! #     list: Option<NestedList>,
+//! #     is_selected: bool,
+//! # }
+//! # #[derive(List)]
+//! # struct NestedList {
+//! #     num: Option<u8>
+//! # }
+//! # let ast: syn::DeriveInput = syn::parse_quote! {
 //! #[my_attr(name = "some_name", is_selected)]
+//! # struct SomeStruct;
+//! # };
+//! # let my_attr = MyAttribute::from_attrs(ast.ident.span(), ast.attrs).unwrap();
+//! # assert_eq!(my_attr.name, "some_name");
+//! # assert!(my_attr.is_selected && my_attr.list.is_none());
 //! ```
 //! <br/>
 //!
@@ -105,28 +265,16 @@
 //!     is_selected: bool,
 //! }
 //!     #[derive(List)]
-//!     pub struct NestedList {
+//!     struct NestedList {
 //!         num: Option<u8>
 //!     }
 //!
 //!
+//! # #[cfg(any())]
 //! #[proc_macro_derive(YOUR_MACRO_NAME, attributes(my_attr))]
 //! pub fn derive_my_trait(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
 //!     let ast = parse_macro_input!(tokens as DeriveInput);
 //!
-//!     fn attempt_derive(ast: DeriveInput) -> Result<TokenStream2, Vec<syn::Error>> {
-//!         // Wrapping an attribute in an option makes it optional
-//!         // A missing error won't be returnwd
-//!         let maybe_attribute = <Option<MyAttribute>>::from_attrs(ast.ident.span(), &ast.attrs)?;
-//!
-//!         let output: TokenStream2 = {
-//!             // Your Macro Generation Code
-//!         };
-//!
-//!         Ok(output)
-//!     }
-//!
-//!
 //!     let generated_tokens = 
 //!         match attempt_derive(ast) {
 //!             Ok(tokens) => tokens,
@@ -138,14 +286,47 @@
 //!
 //!     generated_tokens.into()
 //! }
+//!
+//! fn attempt_derive(ast: DeriveInput) -> Result<TokenStream2, Vec<syn::Error>> {
+//!     // Wrapping an attribute in an option makes it optional
+//!     // A missing error won't be returnwd
+//!     // Deprecated arguments are returned as warnings next to the result
+//!     let (maybe_attribute, warnings) = <Option<MyAttribute>>::from_attrs_with_warnings(ast.ident.span(), ast.attrs);
+//!     let maybe_attribute = maybe_attribute?;
+//!
+//!     // The warnings are shown by adding them to the output
+//!     let warnings = warnings.iter().map(|warning| warning.to_tokens());
+//!
+//!     let output: TokenStream2 = quote!{
+//!         // Your Macro Generation Code
+//!     };
+//!
+//!     Ok(quote!(#(#warnings)* #output))
+//! }
 //! ```
 //!
 //! Another crate using our macro
 //!
 //! ```rust
+//! # use derive_attribute::{Attribute, List};
+//! # #[derive(Attribute)]
+//! # #[attr(name = "my_attr")]
+//! # struct MyAttribute {
+//! #     name: String,
+//! #     list: Option<NestedList>,
+//! #     is_selected: bool,
+//! # }
+//! # #[derive(List)]
+//! # struct NestedList {
+//! #     num: Option<u8>
+//! # }
+//! # let ast: syn::DeriveInput = syn::parse_quote! {
 //! #[derive(YOUR_MACRO_NAME)]
 //! #[my_attr(name = "some_name", is_selected)]
 //! struct SomeStruct;
+//! # };
+//! # let my_attr = MyAttribute::from_attrs(ast.ident.span(), ast.attrs).unwrap();
+//! # assert!(my_attr.name == "some_name" && my_attr.is_selected);
 //! ```
 //!
 //! <br/>
@@ -156,9 +337,9 @@
 //!
 //! proc-macro crate:
 //! ```rust
-//! use derive_attribute::{CustomArg, CustomArgFromMeta};
+//! use derive_attribute::{CustomArg, CustomArgFromMeta, ErrorMsg::{self, InvalidType}, SynVersion};
 //!
-//! struct ErrorType {
+//! enum ErrorType {
 //!     Warning,
 //!     Severe
 //! }
@@ -166,9 +347,9 @@
 //! // Any type that implements 'TryFromMeta' can be deserialized however its a bit verbose
 //! // In order to simplify the implementation we can implement 'CustomArgFromMeta' instead and wrap our type in the 'CustomArg' struct
 //! impl<V: SynVersion> CustomArgFromMeta<V> for ErrorType {
-//!     fn try_from_meta(meta: Self::Metadata) -> Result<Self, ErrorMsg> {
+//!     fn try_from_meta(meta: V::ArgMeta) -> Result<Self, ErrorMsg> {
 //!         let maybe_error_kind = 
-//!             match V::deserialize_string(meta) {
+//!             match V::deserialize_string(&meta) {
 //!                 Some(string) => {
 //!                     match string.to_string().as_str() {
 //!                         "warning" => Some(Self::Warning),
@@ -190,6 +371,20 @@
 //!
 //! Our attribute struct now looks like this: 
 //! ```rust
+//! # use derive_attribute::{Attribute, CustomArg, CustomArgFromMeta, ErrorMsg, SynVersion};
+//! # enum ErrorType {
+//! #     Warning,
+//! #     Severe
+//! # }
+//! # impl<V: SynVersion> CustomArgFromMeta<V> for ErrorType {
+//! #     fn try_from_meta(meta: V::ArgMeta) -> Result<Self, ErrorMsg> {
+//! #         match V::deserialize_string(&meta).as_deref() {
+//! #             Some("warning") => Ok(Self::Warning),
+//! #             Some("severe") => Ok(Self::Severe),
+//! #             _ => Err(ErrorMsg::InvalidType { expected: r#" "warning" or "severe" "# })
+//! #         }
+//! #     }
+//! # }
 //! #[derive(Attribute)]
 //! #[attr(name = "my_attr")]
 //! struct MyAttribute {
@@ -203,9 +398,35 @@
 //! ```
 //! Another crate using our macro:
 //! ```rust
+//! # use derive_attribute::{Attribute, CustomArg, CustomArgFromMeta, ErrorMsg, SynVersion};
+//! # enum ErrorType {
+//! #     Warning,
+//! #     Severe
+//! # }
+//! # impl<V: SynVersion> CustomArgFromMeta<V> for ErrorType {
+//! #     fn try_from_meta(meta: V::ArgMeta) -> Result<Self, ErrorMsg> {
+//! #         match V::deserialize_string(&meta).as_deref() {
+//! #             Some("warning") => Ok(Self::Warning),
+//! #             Some("severe") => Ok(Self::Severe),
+//! #             _ => Err(ErrorMsg::InvalidType { expected: r#" "warning" or "severe" "# })
+//! #         }
+//! #     }
+//! # }
+//! # #[derive(Attribute)]
+//! # #[attr(name = "my_attr")]
+//! # struct MyAttribute {
+//! #     error_type: CustomArg<ErrorType>,
+//! #     name: String,
+//! #     list: Option<u32>,
+//! #     is_selected: bool,
+//! # }
+//! # let ast: syn::DeriveInput = syn::parse_quote! {
 //! #[derive(YOUR_MACRO_NAME)]
-//! #[error(error_type = "warning", name = "some_name", is_selected)]
+//! #[my_attr(error_type = "warning", name = "some_name", is_selected)]
 //! struct Test;
+//! # };
+//! # let my_attr = MyAttribute::from_attrs(ast.ident.span(), ast.attrs).unwrap();
+//! # assert!(matches!(my_attr.error_type, CustomArg(ErrorType::Warning)));
 //! ```
 
 
@@ -213,6 +434,7 @@
 pub use derive_attribute_utils::*;
 pub use derive_attribute_macros::*;
 
+#[allow(unused_imports)]
 mod prelude {
     pub use derive_attribute_utils::{GetSpan, Attribute, TryFromMeta};
 }
\ No newline at end of file
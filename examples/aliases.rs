@@ -0,0 +1,71 @@
+mod common;
+
+use derive_attribute::{Attribute, Warning};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "field")]
+struct Field {
+    #[attr(alias = "rename", alias = "title")]
+    name: Option<String>,
+    #[attr(alias = "colour", deprecated_alias = "use `color` instead")]
+    color: Option<String>,
+    #[attr(alias = "ignore", deprecated = "fields are never skipped")]
+    skip: bool,
+}
+
+fn warning_messages(warnings: Vec<Warning>) -> Vec<String> {
+    warnings.into_iter().map(|warning| warning.msg).collect()
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[field(title = "Name", colour = "red", skip)]
+            struct Test;
+        };
+
+    let (field, warnings) = Field::from_attrs_with_warnings(tokens.span(), tokens.attrs);
+    let field = field.unwrap();
+    println!("{:?}", field);
+    assert_eq!(field.name.as_deref(), Some("Name"));
+    assert_eq!(field.color.as_deref(), Some("red"));
+    assert!(field.skip);
+
+    let warnings = warning_messages(warnings);
+    println!("{:?}", warnings);
+    assert_eq!(warnings, ["'colour' is deprecated: use `color` instead", "'skip' is deprecated: fields are never skipped"]);
+
+    // a deprecated alias doesn't deprecate the field's key, a deprecated field warns under its aliases too
+    let tokens: DeriveInput = parse_quote!{ #[field(name = "Name", color = "red", ignore)] struct Test; };
+    let (field, warnings) = Field::from_attrs_with_warnings(tokens.span(), tokens.attrs);
+    let field = field.unwrap();
+    assert_eq!(field.color.as_deref(), Some("red"));
+    assert!(field.skip);
+    assert_eq!(warning_messages(warnings), ["'ignore' is deprecated: fields are never skipped"]);
+
+    // the warnings are returned when deserializing fails and don't carry over to the next attribute
+    let tokens: DeriveInput = parse_quote!{ #[field(colour = "red", name = 1)] struct Test; };
+    let (field, warnings) = Field::from_attrs_with_warnings(tokens.span(), tokens.attrs);
+    assert!(field.is_err());
+    assert_eq!(warning_messages(warnings), ["'colour' is deprecated: use `color` instead"]);
+
+    let tokens: DeriveInput = parse_quote!{ #[field(colour = "red", name = 1)] struct Test; };
+    assert!(Field::from_attrs(tokens.span(), tokens.attrs).is_err());
+    let tokens: DeriveInput = parse_quote!{ #[field(color = "red")] struct Test; };
+    let (_, warnings) = Field::from_attrs_with_warnings(tokens.span(), tokens.attrs);
+    assert!(warnings.is_empty());
+
+    // an alias is the same argument as the field
+    let tokens: DeriveInput = parse_quote!{ #[field(name = "a", rename = "b")] struct Test; };
+    let errors = error_messages(Field::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Duplicate Argument: 'name' is already defined", "first defined here"]);
+}
+
+#[test]
+fn aliases() {
+    main();
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use std::num::{NonZeroU8, NonZeroI32, Wrapping};
+
+use derive_attribute::{Attribute, Bounded};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "pool")]
+struct Pool {
+    workers: NonZeroU8,
+    offset: Option<NonZeroI32>,
+    // a value that doesn't fit wraps around
+    seed: Wrapping<u32>,
+    // an integer that must be in 1..=10
+    retries: Bounded<u8, 1, 10>,
+    priority: Option<Bounded<i16, -5, 5>>,
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[pool(workers = 4, offset = -1, seed = 42, retries = 10, priority = -5)]
+            struct Test;
+        };
+
+    let pool = Pool::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", pool);
+    assert_eq!(pool.workers.get(), 4);
+    assert_eq!(pool.offset.map(NonZeroI32::get), Some(-1));
+    assert_eq!(pool.seed, Wrapping(42));
+    assert_eq!(*pool.retries, 10);
+    assert_eq!(pool.priority.map(Bounded::get), Some(-5));
+
+    let tokens: DeriveInput = parse_quote!{ #[pool(workers = 0, offset = 0, seed = 1, retries = 0, priority = 6)] struct Test; };
+    let errors = error_messages(Pool::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, [
+        "Invalid Value: NonZeroU8 can't be zero",
+        "Invalid Value: NonZeroI32 can't be zero",
+        "Invalid Value: expected value in 1..=10, found 0",
+        "Invalid Value: expected value in -5..=5, found 6"
+    ]);
+
+    let tokens: DeriveInput = parse_quote!{ #[pool(workers = 1, seed = -1, retries = 1)] struct Test; };
+    let pool = Pool::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert_eq!(pool.seed, Wrapping(u32::MAX));
+
+    let tokens: DeriveInput = parse_quote!{ #[pool(workers = 1, seed = 4294967297, retries = 1)] struct Test; };
+    let pool = Pool::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert_eq!(pool.seed, Wrapping(1));
+
+    let tokens: DeriveInput = parse_quote!{ #[pool(workers = 256, seed = 1.5, retries = 300)] struct Test; };
+    let errors = error_messages(Pool::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, [
+        "Invalid Value: `256` is out of range for NonZeroU8",
+        "Invalid Type: expected u32, found float `1.5`",
+        "Invalid Value: `300` is out of range for u8"
+    ]);
+}
+
+#[test]
+fn bounded_integers() {
+    main();
+}
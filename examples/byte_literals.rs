@@ -0,0 +1,54 @@
+mod common;
+
+use derive_attribute::{Attribute, ByteLit};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "format")]
+struct Format {
+    #[attr(range = "' '..='~'")]
+    delimiter: char,
+    // a byte or an integer
+    terminator: ByteLit<u8>,
+    // a byte string or an array of bytes
+    #[attr(len = "4..=4")]
+    magic: ByteLit<Vec<u8>>,
+    padding: Option<ByteLit<Vec<u8>>>,
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[format(delimiter = ',', terminator = b'\n', magic = b"\x7FELF", padding = [b'-', 0])]
+            struct Test;
+        };
+
+    let format = Format::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", format);
+    assert_eq!(format.delimiter, ',');
+    assert_eq!(*format.terminator, b'\n');
+    assert_eq!(*format.magic, b"\x7FELF");
+    assert_eq!(format.padding, Some(ByteLit(vec![b'-', 0])));
+
+    let tokens: DeriveInput = parse_quote!{ #[format(delimiter = ",", terminator = 0, magic = [0x7F, b'E'])] struct Test; };
+    let errors = error_messages(Format::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Type: expected char", "Invalid Length: expected length in 4..=4, found 2"]);
+
+    let tokens: DeriveInput = parse_quote!{ #[format(delimiter = 'é', terminator = "\n", magic = b"ab", padding = [256])] struct Test; };
+    let errors = error_messages(Format::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, [
+        "Invalid Value: expected value in ' '..='~', found é",
+        "Invalid Type: expected byte or u8",
+        "Invalid Length: expected length in 4..=4, found 2",
+        "Invalid Value: `256` is out of range for u8"
+    ]);
+}
+
+#[test]
+fn byte_literals() {
+    main();
+}
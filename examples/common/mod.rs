@@ -0,0 +1,4 @@
+/// Gets the message of every error, including the notes combined into them.
+pub fn error_messages(errors: Vec<syn::Error>) -> Vec<String> {
+    errors.into_iter().flatten().map(|error| error.to_string()).collect()
+}
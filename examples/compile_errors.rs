@@ -0,0 +1,10 @@
+// checks the output of the derives against the .stderr file next to each file in ui/
+fn main() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("ui/*.rs");
+}
+
+#[test]
+fn compile_errors() {
+    main();
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use derive_attribute::Attribute;
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "source", one_of(path, url), at_least_one_of(read, write))]
+struct Source {
+    path: Option<String>,
+    url: Option<String>,
+    #[attr(requires = "url")]
+    timeout: Option<u32>,
+    read: bool,
+    #[attr(conflicts_with = "cached")]
+    write: bool,
+    cached: bool,
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[source(url = "https://example.com", timeout = 30, read, cached)]
+            struct Test;
+        };
+
+    let source = Source::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", source);
+    assert_eq!(source.url.as_deref(), Some("https://example.com"));
+    assert_eq!(source.timeout, Some(30));
+    assert!(source.path.is_none() && source.read && !source.write && source.cached);
+
+    let tokens: DeriveInput = parse_quote!{ #[source(path = "a.txt", timeout = 30, write, cached)] struct Test; };
+    let errors = error_messages(Source::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, [
+        "Missing Argument: 'timeout' requires 'url'",
+        "Conflicting Arguments: 'write' can't be used with 'cached'",
+        "Conflicting Arguments: 'cached' can't be used with 'write'"
+    ]);
+
+    // each argument of a group that's used too many times gets an error
+    let tokens: DeriveInput = parse_quote!{ #[source(path = "a.txt", url = "https://example.com")] struct Test; };
+    let errors = error_messages(Source::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, [
+        "Conflicting Arguments: only one of 'path', 'url' can be used",
+        "Conflicting Arguments: only one of 'path', 'url' can be used",
+        "Missing Argument: expected at least one of 'read', 'write'"
+    ]);
+
+    let tokens: DeriveInput = parse_quote!{ #[source(read)] struct Test; };
+    let errors = error_messages(Source::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Missing Argument: expected one of 'path', 'url'"]);
+}
+
+#[test]
+fn constraints() {
+    main();
+}
@@ -13,15 +13,13 @@ struct MyAttr {
     feeling: CustomArg<Feeling>
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub enum Feeling {
     Happy,
+    #[default]
     Neutral,
     Sad
 }
-impl Default for Feeling {
-    fn default() -> Self { Self::Neutral }
-}
 
 impl<V: SynVersion> CustomArgFromMeta<V> for Feeling {
     fn try_from_meta(meta: V::ArgMeta) -> Result<Self, derive_attribute::ErrorMsg> {
@@ -12,15 +12,13 @@ struct MyAttr {
     feeling: Feeling
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub enum Feeling {
     Happy,
+    #[default]
     Neutral,
     Sad
 }
-impl Default for Feeling {
-    fn default() -> Self { Self::Neutral }
-}
 
 impl Concat for Feeling {}
 impl<V: SynVersion> TryFromMeta<V> for Feeling {
@@ -0,0 +1,73 @@
+mod common;
+
+use derive_attribute::{Attribute, List};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "server")]
+struct Server {
+    // a default can be a path to a function in another module
+    #[attr(default = "crate::defaults::port")]
+    port: u16,
+    // or any expression
+    #[attr(default_expr = "String::from(\"localhost\")")]
+    host: String,
+    #[attr(default_expr = "Vec::new()")]
+    routes: Vec<String>,
+    #[attr(default_expr = "4 * 2")]
+    workers: u32,
+    #[attr(default_expr = "Limits { connections: 64, timeout: 30 }")]
+    limits: Limits,
+}
+
+#[derive(Debug, List)]
+struct Limits {
+    connections: u32,
+    timeout: u32,
+}
+
+mod defaults {
+    pub fn port() -> u16 {
+        8080
+    }
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[server(routes = ["/", "/users"])]
+            struct Test;
+        };
+
+    let server = Server::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", server);
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.routes, ["/", "/users"]);
+    assert_eq!(server.workers, 8);
+    assert_eq!(server.limits.connections, 64);
+    assert_eq!(server.limits.timeout, 30);
+
+    let tokens: DeriveInput = parse_quote!{ #[server(port = 80, host = "example.com", workers = 2, limits(connections = 8, timeout = 5))] struct Test; };
+    let server = Server::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", server);
+    assert_eq!(server.port, 80);
+    assert_eq!(server.host, "example.com");
+    assert!(server.routes.is_empty());
+    assert_eq!(server.workers, 2);
+    assert_eq!(server.limits.connections, 8);
+    assert_eq!(server.limits.timeout, 5);
+
+    // a default isn't used when the argument is given with an error
+    let tokens: DeriveInput = parse_quote!{ #[server(workers = "many")] struct Test; };
+    let errors = error_messages(Server::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Type: expected u32"]);
+}
+
+#[test]
+fn defaults() {
+    main();
+}
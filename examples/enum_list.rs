@@ -0,0 +1,56 @@
+mod common;
+
+use derive_attribute::{Attribute, List};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "my_attr")]
+struct MyAttr {
+    source: Source,
+    fallback: Option<Source>,
+}
+
+#[derive(Debug, PartialEq, List)]
+enum Source {
+    File(FileArgs),
+    Env { var: String },
+    Stdin,
+}
+    #[derive(Debug, PartialEq, List)]
+    struct FileArgs {
+        path: String
+    }
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[my_attr(source(file(path = "config.toml")), fallback(env(var = "CONFIG")))]
+            struct Test;
+        };
+
+    let my_attr = MyAttr::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", my_attr);
+    assert_eq!(my_attr.source, Source::File(FileArgs { path: "config.toml".to_string() }));
+    assert_eq!(my_attr.fallback, Some(Source::Env { var: "CONFIG".to_string() }));
+
+    let tokens: DeriveInput = parse_quote!{ #[my_attr(source(stdin))] struct Test; };
+    let my_attr = MyAttr::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert_eq!(my_attr.source, Source::Stdin);
+    assert_eq!(my_attr.fallback, None);
+
+    // exactly one variant has to be given
+    let tokens: DeriveInput = parse_quote!{ #[my_attr(source(), fallback(stdin, env(var = "CONFIG")))] struct Test; };
+    let errors = error_messages(MyAttr::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, [
+        "Missing Variant: expected one of 'file', 'env', 'stdin'",
+        "Conflicting Variants: only one of 'file', 'env', 'stdin' can be used"
+    ]);
+}
+
+#[test]
+fn enum_list() {
+    main();
+}
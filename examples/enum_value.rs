@@ -0,0 +1,111 @@
+mod common;
+
+use derive_attribute::{Attribute, AttrValue};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "my_attr")]
+struct MyAttr {
+    mode: Mode,
+    level: Option<Level>,
+    default: Option<DefaultArg>,
+    limit: Option<Limit>,
+}
+
+#[derive(Debug, PartialEq, AttrValue)]
+enum Mode {
+    Fast,
+    #[attr(name = "slow_and_steady")]
+    Slow,
+}
+
+#[derive(Debug, PartialEq, AttrValue)]
+#[attr(discriminant)]
+enum Level {
+    Low = 1,
+    High = 5,
+}
+
+#[derive(Debug, PartialEq, AttrValue)]
+#[attr(untagged)]
+enum DefaultArg {
+    UseDefault(bool),
+    Path(String),
+}
+
+#[derive(Debug, PartialEq, AttrValue)]
+#[attr(untagged, rename_all = "SCREAMING_SNAKE_CASE")]
+enum Limit {
+    SmallCount(u8),
+    Unlimited(bool),
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[my_attr(mode = fast, level = 5, default = "my_default")]
+            struct Test;
+        };
+
+    let my_attr = MyAttr::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", my_attr);
+    assert_eq!(my_attr.mode, Mode::Fast);
+    assert_eq!(my_attr.level, Some(Level::High));
+    assert_eq!(my_attr.default, Some(DefaultArg::Path("my_default".to_string())));
+
+    // an untagged enum uses the first variant that can deserialize the value
+    let tokens: DeriveInput = parse_quote!{ #[my_attr(mode = fast, default)] struct Test; };
+    let my_attr = MyAttr::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert_eq!(my_attr.default, Some(DefaultArg::UseDefault(true)));
+
+    let tokens: DeriveInput = parse_quote!{ #[my_attr(mode = fast, default = 1)] struct Test; };
+    let errors = error_messages(MyAttr::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Type: expected boolean or string"]);
+
+    let tokens: DeriveInput = parse_quote!{ #[my_attr(mode = fast, limit = 10)] struct Test; };
+    let my_attr = MyAttr::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert_eq!(my_attr.limit, Some(Limit::SmallCount(10)));
+
+    // a variant whose type doesn't report what it expected is described by its key
+    let tokens: DeriveInput = parse_quote!{ #[my_attr(mode = fast, limit = 300)] struct Test; };
+    let errors = error_messages(MyAttr::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Type: expected SMALL_COUNT or boolean"]);
+
+    // a renamed variant is only matched by its new name
+    let tokens: DeriveInput = parse_quote!{ #[my_attr(mode = slow_and_steady, level = 1)] struct Test; };
+    let my_attr = MyAttr::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert_eq!(my_attr.mode, Mode::Slow);
+    assert_eq!(my_attr.level, Some(Level::Low));
+
+    let tokens: DeriveInput = parse_quote!{ #[my_attr(mode = slow, level = 3)] struct Test; };
+    let errors = error_messages(MyAttr::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    // the error lists every allowed variant
+    assert_eq!(errors, [
+        "Invalid Type: expected one of 'fast', 'slow_and_steady'",
+        "Invalid Type: expected one of 'low', 'high' or a discriminant"
+    ]);
+
+    // a variant can also be given as a string, a discriminant enum still accepts the variant's name
+    let tokens: DeriveInput = parse_quote!{ #[my_attr(mode = "fast", level = high)] struct Test; };
+    let my_attr = MyAttr::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert_eq!(my_attr.mode, Mode::Fast);
+    assert_eq!(my_attr.level, Some(Level::High));
+
+    let tokens: DeriveInput = parse_quote!{ #[my_attr(mode = 1, level = 1.5)] struct Test; };
+    let errors = error_messages(MyAttr::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, [
+        "Invalid Type: expected one of 'fast', 'slow_and_steady'",
+        "Invalid Type: expected one of 'low', 'high' or a discriminant"
+    ]);
+}
+
+#[test]
+fn enum_value() {
+    main();
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use derive_attribute::Attribute;
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "buffer")]
+struct Buffer {
+    offset: i32,
+    ratio: Option<f32>,
+    // expressions are only evaluated for fields with eval
+    #[attr(eval)]
+    size: u32,
+    #[attr(eval)]
+    mask: Option<u64>,
+    #[attr(eval)]
+    min: Option<i8>,
+    #[attr(eval)]
+    align: Option<u8>,
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[buffer(offset = -4, ratio = -0.5, size = 4 * 1024, mask = (1 << 10) - 1, min = -128, align = (3 + 11) % 5)]
+            struct Test;
+        };
+
+    let buffer = Buffer::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", buffer);
+    assert_eq!(buffer.offset, -4);
+    assert_eq!(buffer.ratio, Some(-0.5));
+    assert_eq!(buffer.size, 4096);
+    assert_eq!(buffer.mask, Some(1023));
+    assert_eq!(buffer.min, Some(-128));
+    assert_eq!(buffer.align, Some(4));
+
+    // errors point at the part of the expression that failed
+    let tokens: DeriveInput = parse_quote!{ #[buffer(offset = 4 * 2, size = 10 / (5 - 5), mask = 1 << 64, min = 1.5 * 2, align = 2 * (200 + 100))] struct Test; };
+    let errors = error_messages(Buffer::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, [
+        "Invalid Type: expected i32",
+        "Invalid Value: `10 / (5 - 5)` divides by zero",
+        "Invalid Value: `1 << 64` is out of range for u64",
+        "Invalid Type: expected i8, found float `1.5`",
+        "Invalid Value: `200 + 100` is out of range for u8"
+    ]);
+}
+
+#[test]
+fn expressions() {
+    main();
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use derive_attribute::{Attribute, List};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "route")]
+struct Route {
+    path: String,
+    #[attr(flatten)]
+    common: Common,
+}
+
+#[derive(Debug, Attribute)]
+#[attr(name = "handler")]
+struct Handler {
+    name: String,
+    #[attr(flatten)]
+    common: Common,
+    #[attr(flatten)]
+    retry: Retry,
+}
+
+// the shared arguments are written as if they were part of each attribute
+#[derive(Debug, List)]
+struct Common {
+    timeout: Option<u32>,
+    log: bool,
+}
+
+// used when none of its keys are given
+#[derive(Debug, List)]
+#[attr(default = "Retry::none")]
+struct Retry {
+    attempts: u8,
+    delay: u32,
+}
+impl Retry {
+    fn none() -> Self {
+        Self { attempts: 0, delay: 0 }
+    }
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[route(path = "/users", timeout = 30)]
+            #[handler(name = "list_users", log)]
+            struct Test;
+        };
+
+    let route = Route::from_attrs(tokens.span(), tokens.attrs.clone()).unwrap();
+    println!("{:?}", route);
+    assert_eq!(route.path, "/users");
+    assert_eq!(route.common.timeout, Some(30));
+    assert!(!route.common.log);
+
+    let handler = Handler::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", handler);
+    assert_eq!(handler.name, "list_users");
+    assert_eq!(handler.common.timeout, None);
+    assert!(handler.common.log);
+    assert_eq!((handler.retry.attempts, handler.retry.delay), (0, 0));
+
+    // once one of its keys is given the rest are required
+    let tokens: DeriveInput = parse_quote!{ #[handler(name = "list_users", attempts = 3)] struct Test; };
+    let errors = error_messages(Handler::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Missing Argument: 'delay' is required"]);
+
+    // a flattened key has the same errors as any other key
+    let tokens: DeriveInput =
+        parse_quote!{
+            #[route(path = "/users", timeout = "slow")]
+            #[handler(name = "list_users", retries = 3)]
+            struct Test;
+        };
+
+    let errors = error_messages(Route::from_attrs(tokens.span(), tokens.attrs.clone()).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Type: expected u32"]);
+
+    let errors = error_messages(Handler::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Argument"]);
+}
+
+#[test]
+fn flatten() {
+    main();
+}
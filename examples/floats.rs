@@ -0,0 +1,60 @@
+mod common;
+
+use derive_attribute::Attribute;
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "shape")]
+struct Shape {
+    ratio: f32,
+    // an integer is converted to a float
+    scale: f64,
+    size: Option<f32>,
+    // non-finite values are written as strings EX) "inf"
+    #[attr(non_finite)]
+    limit: Option<f64>,
+    weight: Option<f64>,
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[shape(ratio = 0.5, scale = 2, size = 1.5e3f32, limit = "inf", weight = 2.5e-3)]
+            struct Test;
+        };
+
+    let shape = Shape::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", shape);
+    assert_eq!(shape.ratio, 0.5);
+    assert_eq!(shape.scale, 2.0);
+    assert_eq!(shape.size, Some(1500.0));
+    assert_eq!(shape.limit, Some(f64::INFINITY));
+    assert_eq!(shape.weight, Some(0.0025));
+
+    let tokens: DeriveInput = parse_quote!{ #[shape(ratio = 0.5, scale = 1, limit = "NaN")] struct Test; };
+    let shape = Shape::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert!(shape.limit.is_some_and(f64::is_nan));
+
+    // only a field with `non_finite` accepts these strings
+    let tokens: DeriveInput = parse_quote!{ #[shape(ratio = 1u8, scale = 0.5f32, size = 1e40, weight = "NaN")] struct Test; };
+    let errors = error_messages(Shape::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, [
+        "Invalid Type: expected f32, found integer `1u8`",
+        "Invalid Value: expected f64, found `0.5f32` with the suffix f32",
+        "Invalid Value: `1e40` is out of range for f32",
+        "Invalid Type: expected f64"
+    ]);
+
+    let tokens: DeriveInput = parse_quote!{ #[shape(ratio = "0.5", scale = 1, limit = "1.5")] struct Test; };
+    let errors = error_messages(Shape::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Type: expected f32", "Invalid Type: expected f64"]);
+}
+
+#[test]
+fn floats() {
+    main();
+}
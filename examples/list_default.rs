@@ -0,0 +1,78 @@
+mod common;
+
+use derive_attribute::{Attribute, List};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "cache")]
+struct Cache {
+    limits: Limits,
+    retry: Retry,
+    log: Option<Log>,
+}
+
+// used when the list isn't found
+#[derive(Debug, Default, List)]
+#[attr(default)]
+struct Limits {
+    entries: u32,
+    bytes: u64,
+}
+
+#[derive(Debug, List)]
+#[attr(default = "Retry::none")]
+struct Retry {
+    attempts: u8,
+    #[attr(default_expr = "100")]
+    delay: u32,
+}
+impl Retry {
+    fn none() -> Self {
+        Self { attempts: 0, delay: 0 }
+    }
+}
+
+#[derive(Debug, List)]
+struct Log {
+    #[attr(default_expr = "String::from(\"info\")")]
+    level: String,
+    #[attr(default)]
+    color: bool,
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[cache]
+            struct Test;
+        };
+
+    let cache = Cache::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", cache);
+    assert_eq!((cache.limits.entries, cache.limits.bytes), (0, 0));
+    assert_eq!((cache.retry.attempts, cache.retry.delay), (0, 0));
+    assert!(cache.log.is_none());
+
+    // a list without arguments uses the default of each field
+    let tokens: DeriveInput = parse_quote!{ #[cache(limits(entries = 10, bytes = 1024), retry(attempts = 3), log)] struct Test; };
+    let cache = Cache::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", cache);
+    assert_eq!((cache.limits.entries, cache.limits.bytes), (10, 1024));
+    assert_eq!((cache.retry.attempts, cache.retry.delay), (3, 100));
+    let log = cache.log.unwrap();
+    assert_eq!(log.level, "info");
+    assert!(!log.color);
+
+    // fields without a default are still required once the list is given
+    let tokens: DeriveInput = parse_quote!{ #[cache(retry)] struct Test; };
+    let errors = error_messages(Cache::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Missing Argument: 'attempts' is required"]);
+}
+
+#[test]
+fn list_default() {
+    main();
+}
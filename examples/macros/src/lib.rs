@@ -0,0 +1,23 @@
+use derive_attribute::Attribute;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+#[allow(dead_code)]
+#[derive(Attribute)]
+#[attr(name = "field")]
+struct Field {
+    #[attr(alias = "colour", deprecated_alias = "use `color` instead")]
+    color: Option<String>,
+}
+
+/// Deserializes `#[field(...)]` and outputs the warnings and errors it produces.
+#[proc_macro_derive(Field, attributes(field))]
+pub fn derive_field(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(tokens as DeriveInput);
+
+    let (field, warnings) = Field::from_attrs_with_warnings(ast.ident.span(), ast.attrs);
+    let warnings = warnings.iter().map(|warning| warning.to_tokens());
+    let errors = field.err().unwrap_or_default().into_iter().map(|error| error.to_compile_error());
+
+    quote!(#(#warnings)* #(#errors)*).into()
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use derive_attribute::Attribute;
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "marker")]
+struct Marker;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "rename")]
+struct Rename {
+    #[attr(value)]
+    to: String,
+    serialize_only: bool,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Attribute)]
+#[attr(name = "options")]
+struct Options {
+    level: Option<u8>,
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[marker]
+            #[rename = "user_id"]
+            struct Test;
+        };
+
+    let marker = <Option<Marker>>::from_attrs(tokens.span(), tokens.attrs.clone()).unwrap();
+    println!("{:?}", marker);
+    assert!(marker.is_some());
+
+    let rename = Rename::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", rename);
+    assert_eq!(rename.to, "user_id");
+    assert!(!rename.serialize_only);
+
+    // the list form still works and a missing marker is None
+    let tokens: DeriveInput = parse_quote!{ #[rename(to = "id", serialize_only)] struct Test; };
+    let marker = <Option<Marker>>::from_attrs(tokens.span(), tokens.attrs.clone()).unwrap();
+    assert!(marker.is_none());
+    let rename = Rename::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert_eq!(rename.to, "id");
+    assert!(rename.serialize_only);
+
+    let tokens: DeriveInput = parse_quote!{ #[marker(hidden)] struct Test; };
+    let errors = error_messages(Marker::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Argument"]);
+
+    let tokens: DeriveInput = parse_quote!{ #[rename = 5] struct Test; };
+    let errors = error_messages(Rename::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Type: expected string"]);
+
+    // only a struct with a value field accepts the name/value form
+    let tokens: DeriveInput = parse_quote!{ #[options = 1] struct Test; };
+    let errors = error_messages(Options::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Type: expected list"]);
+}
+
+#[test]
+fn markers() {
+    main();
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use derive_attribute::Attribute;
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "cli")]
+struct Cli {
+    // a flag that counts how many times it's given
+    #[attr(merge = "count")]
+    verbose: u8,
+    // a flag that's true if any attribute sets it
+    #[attr(merge = "or")]
+    debug: bool,
+    #[attr(merge = "first")]
+    name: Option<String>,
+    #[attr(merge = "last")]
+    level: Option<u32>,
+    #[attr(merge = "append")]
+    tags: Option<Vec<String>>,
+    // lists are appended by default, this makes a repeated list an error
+    #[attr(merge = "error")]
+    paths: Vec<String>,
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[cli(verbose, verbose, name = "first", level = 1, tags = ["a"])]
+            #[cli(verbose, debug, name = "second", level = 2, tags = ["b", "c"])]
+            #[cli(debug = false, paths = ["src"])]
+            struct Test;
+        };
+
+    let cli = Cli::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", cli);
+    assert_eq!(cli.verbose, 3);
+    assert!(cli.debug);
+    assert_eq!(cli.name.as_deref(), Some("first"));
+    assert_eq!(cli.level, Some(2));
+    assert_eq!(cli.tags.unwrap(), ["a", "b", "c"]);
+    assert_eq!(cli.paths, ["src"]);
+
+    // a counted field starts at zero and can be given a count
+    let tokens: DeriveInput = parse_quote!{ #[cli] struct Test; };
+    let cli = Cli::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert_eq!(cli.verbose, 0);
+    assert!(!cli.debug);
+
+    let tokens: DeriveInput = parse_quote!{ #[cli(verbose = 2, verbose)] struct Test; };
+    let cli = Cli::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert_eq!(cli.verbose, 3);
+
+    let tokens: DeriveInput = parse_quote!{ #[cli(paths = ["src"])] #[cli(paths = ["tests"])] struct Test; };
+    let errors = error_messages(Cli::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Duplicate Argument: 'paths' is already defined", "first defined here"]);
+}
+
+#[test]
+fn merge() {
+    main();
+}
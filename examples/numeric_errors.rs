@@ -0,0 +1,46 @@
+mod common;
+
+use derive_attribute::{Attribute, SynVersion, Syn2, NumberError};
+use syn::{parse_quote, DeriveInput, spanned::Spanned, Meta};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "volume")]
+struct Volume {
+    level: u8,
+    step: Option<i16>,
+    gain: Option<u32>,
+}
+
+fn main() {
+    let tokens: DeriveInput = parse_quote!{ #[volume(level = 255u8, step = -5, gain = 1_000)] struct Test; };
+    let volume = Volume::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", volume);
+    assert_eq!(volume.level, 255);
+    assert_eq!(volume.step, Some(-5));
+    assert_eq!(volume.gain, Some(1000));
+
+    let tokens: DeriveInput = parse_quote!{ #[volume(level = 300, step = 5u64, gain = 1.5)] struct Test; };
+    let errors = error_messages(Volume::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, [
+        "Invalid Value: `300` is out of range for u8",
+        "Invalid Value: expected i16, found `5u64` with the suffix u64",
+        "Invalid Type: expected u32, found float `1.5`"
+    ]);
+
+    // the reason is also available when deserializing an argument directly
+    let meta: Meta = parse_quote!(step = 5u64);
+    match Syn2::deserialize_integer::<u8>(&meta) {
+        Err(NumberError::SuffixMismatch(number)) => assert_eq!(number.suffix, "u64"),
+        other => panic!("expected a suffix mismatch, found {:?}", other)
+    }
+
+    let meta: Meta = parse_quote!(step = "5");
+    assert!(matches!(Syn2::deserialize_integer::<u8>(&meta), Err(NumberError::NotANumber)));
+}
+
+#[test]
+fn numeric_errors() {
+    main();
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use derive_attribute::{Attribute, AttrValue};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+struct Route {
+    #[attr(positional)]
+    path: String,
+    #[attr(positional)]
+    method: Option<Method>,
+    strict: bool,
+}
+
+#[derive(Debug, PartialEq, AttrValue)]
+enum Method {
+    Get,
+    Post,
+}
+
+#[derive(Debug, Attribute)]
+#[attr(name = "alias")]
+struct Alias(String, Option<String>);
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[route("/users", post, strict)]
+            #[alias("people")]
+            struct Test;
+        };
+
+    let route = Route::from_attrs(tokens.span(), tokens.attrs.clone()).unwrap();
+    println!("{:?}", route);
+    assert_eq!(route.path, "/users");
+    assert_eq!(route.method, Some(Method::Post));
+    assert!(route.strict);
+
+    let alias = Alias::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", alias);
+    assert_eq!(alias.0, "people");
+    assert_eq!(alias.1, None);
+
+    // a positional field can also be given by its key
+    let tokens: DeriveInput = parse_quote!{ #[route(path = "/users")] struct Test; };
+    let route = Route::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert_eq!(route.path, "/users");
+    assert_eq!(route.method, None);
+
+    let tokens: DeriveInput = parse_quote!{ #[route("/users", get, "extra")] struct Test; };
+    let errors = error_messages(Route::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Too Many Arguments: expected at most 2 positional arguments"]);
+
+    let tokens: DeriveInput = parse_quote!{ #[route(strict, "/users")] struct Test; };
+    let errors = error_messages(Route::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Argument: positional arguments must come before named arguments"]);
+
+    // too few positional arguments is a missing argument
+    let tokens: DeriveInput = parse_quote!{ #[alias()] struct Test; };
+    let errors = error_messages(Alias::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Missing Argument: 'positional argument 1' is required"]);
+}
+
+#[test]
+fn positional() {
+    main();
+}
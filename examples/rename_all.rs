@@ -0,0 +1,62 @@
+mod common;
+
+use derive_attribute::{Attribute, AttrValue, List};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "column", rename_all = "camelCase")]
+struct Column {
+    max_len: Option<u32>,
+    r#type: Option<String>,
+    is_primary_key: bool,
+    format: Option<Format>,
+    source: Option<Source>,
+}
+
+#[derive(Debug, PartialEq, AttrValue)]
+#[attr(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Format {
+    PlainText,
+    Json,
+}
+
+#[derive(Debug, List)]
+#[attr(rename_all = "PascalCase")]
+enum Source {
+    FromEnv(String),
+    Generated,
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[column(maxLen = 32, type = "varchar", isPrimaryKey, format = PLAIN_TEXT, source(FromEnv = "COLUMN"))]
+            struct Test;
+        };
+
+    let column = Column::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", column);
+    assert_eq!(column.max_len, Some(32));
+    assert_eq!(column.r#type.as_deref(), Some("varchar"));
+    assert!(column.is_primary_key);
+    assert_eq!(column.format, Some(Format::PlainText));
+    assert!(matches!(column.source, Some(Source::FromEnv(ref var)) if var == "COLUMN"));
+
+    // the field names are no longer keys
+    let tokens: DeriveInput = parse_quote!{ #[column(max_len = 32)] struct Test; };
+    let errors = error_messages(Column::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Argument"]);
+
+    let tokens: DeriveInput = parse_quote!{ #[column(format = plain_text, source(generated))] struct Test; };
+    let errors = error_messages(Column::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Type: expected one of 'PLAIN_TEXT', 'JSON'", "Invalid Argument"]);
+}
+
+#[test]
+fn rename_all() {
+    main();
+}
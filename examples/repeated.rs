@@ -0,0 +1,81 @@
+mod common;
+
+use derive_attribute::{Attribute, List};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "route")]
+struct Route {
+    #[attr(positional)]
+    path: String,
+    method: Option<String>,
+}
+
+#[derive(Debug, Attribute)]
+#[attr(name = "response")]
+struct Response {
+    include: Vec<String>,
+    header: Vec<Header>,
+    tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, List)]
+struct Header {
+    name: String,
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[route("/users")]
+            #[route("/users", method = "POST")]
+            struct Test;
+        };
+
+    let routes = <Vec<Route>>::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", routes);
+    assert_eq!(routes.len(), 2);
+    assert_eq!((routes[0].path.as_str(), routes[0].method.as_deref()), ("/users", None));
+    assert_eq!((routes[1].path.as_str(), routes[1].method.as_deref()), ("/users", Some("POST")));
+
+    // no instances is an empty Vec
+    let tokens: DeriveInput = parse_quote!{ #[response] struct Test; };
+    let routes = <Vec<Route>>::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert!(routes.is_empty());
+
+    // each instance is validated on its own and the errors are combined
+    let tokens: DeriveInput = parse_quote!{ #[route("/a", method = 1)] #[route("/b")] #[route(method = "GET")] struct Test; };
+    let errors = error_messages(<Vec<Route>>::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Type: expected string", "Missing Argument: 'path' is required"]);
+
+    // repeated keys are collected in order and a missing Vec is empty
+    let tokens: DeriveInput =
+        parse_quote!{
+            #[response(include = "a", header(name = "x"), include = ["b", "c"])]
+            #[response(header(name = "y"), tags = [])]
+            struct Test;
+        };
+    let response = Response::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", response);
+    assert_eq!(response.include, ["a", "b", "c"]);
+    assert_eq!(response.header.iter().map(|header| header.name.as_str()).collect::<Vec<_>>(), ["x", "y"]);
+    assert_eq!(response.tags, Some(vec![]));
+
+    let tokens: DeriveInput = parse_quote!{ #[response] struct Test; };
+    let response = Response::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert!(response.include.is_empty() && response.header.is_empty());
+    assert_eq!(response.tags, None);
+
+    let tokens: DeriveInput = parse_quote!{ #[response(include = "a", include = 1, header(name = "x"), header(value = "y"))] struct Test; };
+    let errors = error_messages(Response::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Type: expected string", "Invalid Argument"]);
+}
+
+#[test]
+fn repeated() {
+    main();
+}
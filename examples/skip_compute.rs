@@ -0,0 +1,62 @@
+mod common;
+
+use derive_attribute::Attribute;
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[allow(dead_code)]
+#[derive(Debug, Attribute)]
+#[attr(name = "table")]
+struct Table {
+    name: String,
+    schema: Option<String>,
+    #[attr(skip)]
+    indexes: Vec<String>,
+    #[attr(skip, default = "default_engine")]
+    engine: String,
+    #[attr(skip, compute = "qualified_name(name, schema)")]
+    qualified_name: String,
+}
+
+fn default_engine() -> String {
+    String::from("InnoDB")
+}
+
+fn qualified_name(name: &String, schema: &Option<String>) -> String {
+    match schema {
+        Some(schema) => format!("{schema}.{name}"),
+        None => name.clone()
+    }
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[table(name = "users", schema = "auth")]
+            struct Test;
+        };
+
+    let table = Table::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", table);
+    assert!(table.indexes.is_empty());
+    assert_eq!(table.engine, "InnoDB");
+    assert_eq!(table.qualified_name, "auth.users");
+
+    // skipped fields can't be given
+    let tokens: DeriveInput = parse_quote!{ #[table(name = "users", engine = "MyISAM")] struct Test; };
+    let errors = error_messages(Table::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Argument"]);
+
+    // computed fields only run once the other fields are valid
+    let tokens: DeriveInput = parse_quote!{ #[table(schema = "auth")] struct Test; };
+    let errors = error_messages(Table::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Missing Argument: 'name' is required"]);
+}
+
+#[test]
+fn skip_compute() {
+    main();
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use derive_attribute::{Attribute, List, Error, ErrorMsg::InvalidValue, FieldSpans};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "range", validate = "validate_range")]
+struct Range {
+    min: i32,
+    max: i32,
+    step: Option<Step>,
+}
+
+#[derive(Debug, List)]
+#[attr(validate = "Step::validate")]
+struct Step {
+    size: u32,
+    count: Option<u32>,
+}
+impl Step {
+    fn validate(&self, spans: &FieldSpans) -> Result<(), Vec<Error>> {
+        match self.count {
+            Some(count) if count < self.size => Err(vec![Error::new(spans.get("count"), InvalidValue(format!("count must be at least {}", self.size)))]),
+            _ => Ok(())
+        }
+    }
+}
+
+// runs once every field is valid and can return more than one error
+fn validate_range(range: &Range, spans: &FieldSpans) -> Result<(), Vec<Error>> {
+    let mut errors = vec![];
+    if range.min >= range.max {
+        errors.push(Error::new(spans.get("min"), InvalidValue(String::from("'min' must be less than 'max'"))));
+    }
+    if range.max > 100 {
+        errors.push(Error::new(spans.get("max"), InvalidValue(String::from("'max' can't be more than 100"))));
+    }
+
+    match errors.len() {
+        0 => Ok(()),
+        _ => Err(errors)
+    }
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[range(min = 1, max = 10, step(size = 2))]
+            struct Test;
+        };
+
+    let range = Range::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", range);
+    assert_eq!((range.min, range.max), (1, 10));
+    assert_eq!(range.step.map(|step| step.size), Some(2));
+
+    let tokens: DeriveInput = parse_quote!{ #[range(min = 200, max = 150)] struct Test; };
+    let errors = error_messages(Range::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Value: 'min' must be less than 'max'", "Invalid Value: 'max' can't be more than 100"]);
+
+    // a nested list is validated before the struct that contains it
+    let tokens: DeriveInput = parse_quote!{ #[range(min = 10, max = 5, step(size = 2, count = 1))] struct Test; };
+    let errors = error_messages(Range::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Value: count must be at least 2"]);
+}
+
+#[test]
+fn struct_validate() {
+    main();
+}
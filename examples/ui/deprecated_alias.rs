@@ -0,0 +1,14 @@
+// the warning is turned into an error so that it's shown in the output
+#![deny(deprecated)]
+
+use example_macros::Field;
+
+#[derive(Field)]
+#[field(colour = "red")]
+struct Renamed;
+
+#[derive(Field)]
+#[field(color = "red")]
+struct Current;
+
+fn main() {}
@@ -0,0 +1,28 @@
+use derive_attribute::{Attribute, List};
+
+#[derive(List)]
+struct Connection {
+    host: String,
+}
+
+#[derive(Attribute)]
+#[attr(name = "server")]
+struct Server {
+    name: String,
+    // skipped and computed fields are never deserialized
+    #[attr(skip, validate = "check_port", range = "1..")]
+    port: u16,
+    #[attr(compute = "make_label(name)", default = "make_label")]
+    label: String,
+    // the rest field only collects unmatched arguments
+    #[attr(rest, alias = "other", merge = "last")]
+    others: Vec<syn::Meta>,
+    // a flattened field's keys belong to the list
+    #[attr(flatten, default, requires = "name")]
+    connection: Connection,
+}
+
+fn check_port(_: &u16) -> Result<(), String> { Ok(()) }
+fn make_label(name: &String) -> String { name.clone() }
+
+fn main() {}
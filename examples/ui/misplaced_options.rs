@@ -0,0 +1,17 @@
+use derive_attribute::{Attribute, AttrValue};
+
+#[derive(Attribute)]
+#[attr(name = "route", requires = "path", positional, skip)]
+struct Route {
+    #[attr(one_of(path, url), untagged, ignore_unknown, rename_all = "camelCase")]
+    path: Option<String>,
+    url: Option<String>,
+}
+
+#[derive(AttrValue)]
+enum Method {
+    #[attr(alias = "fetch")]
+    Get,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use derive_attribute::Attribute;
+
+#[derive(Attribute)]
+#[attr(name = "table")]
+struct Table {
+    name: String,
+    schema: Option<String>,
+    #[attr(compute = "qualified_name(name, shema)")]
+    qualified_name: String,
+}
+
+fn qualified_name(name: &String, _schema: &Option<String>) -> String {
+    name.clone()
+}
+
+fn main() {}
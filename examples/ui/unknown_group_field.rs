@@ -0,0 +1,11 @@
+use derive_attribute::Attribute;
+
+#[derive(Attribute)]
+#[attr(name = "source", one_of(path, ulr), at_least_one_of(path, fiel))]
+struct Source {
+    path: Option<String>,
+    url: Option<String>,
+    file: Option<String>,
+}
+
+fn main() {}
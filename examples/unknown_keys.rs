@@ -0,0 +1,70 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use derive_attribute::{Attribute, List};
+use syn::{parse_quote, DeriveInput, spanned::Spanned, Meta};
+use common::error_messages;
+
+// two macros can read their own keys from the same attribute
+#[derive(Debug, Attribute)]
+#[attr(name = "column", ignore_unknown)]
+struct Column {
+    name: String,
+}
+
+#[derive(Attribute)]
+#[attr(name = "column")]
+struct ColumnIndex {
+    unique: bool,
+    // every argument that isn't a key of this struct
+    #[attr(rest)]
+    rest: Vec<Meta>,
+}
+
+#[derive(Attribute)]
+#[attr(name = "forward")]
+struct Forward {
+    options: Options,
+}
+
+#[derive(List)]
+struct Options {
+    enabled: bool,
+    #[attr(rest)]
+    rest: BTreeMap<String, Meta>,
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[column(name = "id", unique, comment = "primary key")]
+            struct Test;
+        };
+
+    let column = Column::from_attrs(tokens.span(), tokens.attrs.clone()).unwrap();
+    println!("{:?}", column);
+    assert_eq!(column.name, "id");
+
+    let index = ColumnIndex::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert!(index.unique);
+    let rest_keys: Vec<_> = index.rest.iter().map(|meta| meta.path().get_ident().unwrap().to_string()).collect();
+    assert_eq!(rest_keys, ["name", "comment"]);
+
+    let tokens: DeriveInput = parse_quote!{ #[forward(options(enabled, retries = 3, mode = "fast"))] struct Test; };
+    let forward = Forward::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    assert!(forward.options.enabled);
+    assert_eq!(forward.options.rest.keys().collect::<Vec<_>>(), ["mode", "retries"]);
+
+    // a map can only have each key once
+    let tokens: DeriveInput = parse_quote!{ #[forward(options(retries = 3, retries = 4))] struct Test; };
+    let errors = error_messages(Forward::from_attrs(tokens.span(), tokens.attrs).err().unwrap());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Duplicate Argument: 'retries' is already defined", "first defined here"]);
+}
+
+#[test]
+fn unknown_keys() {
+    main();
+}
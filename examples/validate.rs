@@ -0,0 +1,90 @@
+mod common;
+
+use derive_attribute::Attribute;
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "column")]
+struct Column {
+    #[attr(validate = "valid_name")]
+    name: String,
+    #[attr(validate = "validators::non_zero")]
+    width: Option<u32>,
+    #[attr(default = "default_precision", validate = "validators::precision")]
+    precision: u8,
+}
+
+fn default_precision() -> u8 {
+    2
+}
+
+// a validator can return a message
+fn valid_name(name: &String) -> Result<(), String> {
+    match name.chars().all(|c| c.is_ascii_lowercase() || c == '_') {
+        true => Ok(()),
+        false => Err(format!("'{name}' must be snake_case"))
+    }
+}
+
+mod validators {
+    use derive_attribute::ErrorMsg;
+
+    // optional fields are validated as an option
+    pub fn non_zero(width: &Option<u32>) -> Result<(), &'static str> {
+        match width {
+            Some(0) => Err("width can't be zero"),
+            _ => Ok(())
+        }
+    }
+
+    // or one of the crate's errors
+    pub fn precision(precision: &u8) -> Result<(), ErrorMsg> {
+        match *precision <= 10 {
+            true => Ok(()),
+            false => Err(ErrorMsg::InvalidValue(format!("precision {precision} is more than 10")))
+        }
+    }
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[column(name = "user_id", width = 8)]
+            struct Test;
+        };
+
+    let column = Column::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", column);
+    assert_eq!(column.name, "user_id");
+    assert_eq!(column.width, Some(8));
+    assert_eq!(column.precision, 2);
+
+    // every failed validator is reported at once
+    let tokens: DeriveInput = parse_quote!{ #[column(name = "UserId", width = 0, precision = 12)] struct Test; };
+    let errors = error_messages(Column::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, [
+        "Invalid Value: 'UserId' must be snake_case",
+        "Invalid Value: width can't be zero",
+        "Invalid Value: precision 12 is more than 10"
+    ]);
+
+    // a validator's error points at the value it was given, tokens parsed from a string have real locations
+    let tokens: DeriveInput = syn::parse_str(r#"#[column(name = "id", width = 0)] struct Test;"#).unwrap();
+    let errors = Column::from_attrs(tokens.span(), tokens.attrs).unwrap_err();
+    let columns: Vec<usize> = errors.iter().map(|error| error.span().start().column).collect();
+    assert_eq!(columns, [30]);
+
+    // validators only run on values that were deserialized
+    let tokens: DeriveInput = parse_quote!{ #[column(name = 5)] struct Test; };
+    let errors = error_messages(Column::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, ["Invalid Type: expected string"]);
+}
+
+#[test]
+fn validate() {
+    main();
+}
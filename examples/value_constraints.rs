@@ -0,0 +1,71 @@
+mod common;
+
+use derive_attribute::{Attribute, List};
+use syn::{parse_quote, DeriveInput, spanned::Spanned};
+use common::error_messages;
+
+#[derive(Debug, Attribute)]
+#[attr(name = "column")]
+struct Column {
+    #[attr(len = "1..=16", pattern = "^[a-z_]+$")]
+    name: String,
+    #[attr(range = "1..=64")]
+    width: Option<u32>,
+    #[attr(range = "..=100")]
+    percent: u8,
+    // each value in a list is checked
+    #[attr(range = "1..", len = "..=3")]
+    indexes: Vec<u8>,
+    labels: Labels,
+}
+
+#[derive(Debug, List)]
+struct Labels {
+    #[attr(len = "2..", pattern = "^[A-Z]")]
+    short: Option<String>,
+}
+
+fn main() {
+    let tokens: DeriveInput =
+        parse_quote!{
+            // #[derive(MACRO_NAME)]
+            #[column(name = "user_id", width = 8, percent = 50, indexes = [1, 2], labels(short = "Id"))]
+            struct Test;
+        };
+
+    let column = Column::from_attrs(tokens.span(), tokens.attrs).unwrap();
+    println!("{:?}", column);
+    assert_eq!(column.name, "user_id");
+    assert_eq!(column.width, Some(8));
+    assert_eq!(column.percent, 50);
+    assert_eq!(column.indexes, [1, 2]);
+    assert_eq!(column.labels.short.as_deref(), Some("Id"));
+
+    let tokens: DeriveInput =
+        parse_quote!{
+            #[column(name = "UserId", width = 100, percent = 101, indexes = [0, 1, 2, 3], labels(short = "i"))]
+            struct Test;
+        };
+    let errors = error_messages(Column::from_attrs(tokens.span(), tokens.attrs).unwrap_err());
+    println!("{:?}", errors);
+    assert_eq!(errors, [
+        "Invalid Value: expected value matching \"^[a-z_]+$\", found \"UserId\"",
+        "Invalid Value: expected value in 1..=64, found 100",
+        "Invalid Value: expected value in ..=100, found 101",
+        "Invalid Value: expected value in 1.., found 0",
+        "Invalid Length: expected length in ..=3, found 4",
+        "Invalid Length: expected length in 2.., found 1",
+        "Invalid Value: expected value matching \"^[A-Z]\", found \"i\""
+    ]);
+
+    // errors about a value point at the value instead of its key, tokens parsed from a string have real locations
+    let tokens: DeriveInput = syn::parse_str(r#"#[column(name = "id", width = 100, percent = 0, labels())] struct Test;"#).unwrap();
+    let errors = Column::from_attrs(tokens.span(), tokens.attrs).unwrap_err();
+    let columns: Vec<usize> = errors.iter().map(|error| error.span().start().column).collect();
+    assert_eq!(columns, [30]);
+}
+
+#[test]
+fn value_constraints() {
+    main();
+}